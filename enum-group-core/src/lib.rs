@@ -0,0 +1,81 @@
+// @author:    olinex
+// @time:      2022/07/26
+
+//! Runtime traits for the enum types which group their variants.
+//!
+//! The `EnumGroup` derive macro re-exported by this crate generates the same inherent functions
+//! as the `enum-group` crate does, and also implements [`EnumGroup`] and [`EnumGroupMeta`],
+//! so that generic code can be written over any grouped enum.
+//!
+//! ```rust
+//! use enum_group_core::EnumGroup;
+//!
+//! #[derive(EnumGroup)]
+//! enum Number {
+//!     #[groups(odd)]
+//!     One,
+//!
+//!     #[groups(even, prime)]
+//!     Two,
+//! }
+//!
+//! fn describe(value: &dyn EnumGroup) -> String {
+//!     format!("{}: {}", value.variant_name(), value.group_names().join(", "))
+//! }
+//!
+//! assert_eq!(describe(&Number::One), "One: odd");
+//! assert_eq!(Number::Two.in_group_named("prime"), Some(true));
+//! ```
+//!
+//! If this crate is re-exported by another crate,
+//! use `#[enum_group(crate = path::to::enum_group_core)]` to tell the derive macro where it is.
+
+// self mods
+
+// use other mods
+
+// use self mods
+
+pub use enum_group::EnumGroupCore as EnumGroup;
+
+/// The name, description and variants of a group, shared by all the enums which group their variants.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct GroupDescriptor {
+    /// The group label name.
+    pub name: &'static str,
+
+    /// The description declared by `#[enum_group(describe(label = "text"))]`.
+    pub description: Option<&'static str>,
+
+    /// The names of the variants in the group.
+    pub variants: &'static [&'static str],
+}
+
+/// The object-safe runtime view of an enum which groups its variants.
+pub trait EnumGroup {
+    /// Returns the name of the variant.
+    fn variant_name(&self) -> &'static str;
+
+    /// Returns the group label names which the variant belongs to.
+    fn group_names(&self) -> &'static [&'static str];
+
+    /// Tells whether the variant belongs to the group label name,
+    /// returns `None` if the group label name is unknown.
+    fn in_group_named(&self, name: &str) -> Option<bool>;
+}
+
+/// The static metadata of an enum which groups its variants.
+///
+/// Associated constants would make [`EnumGroup`] not object-safe,
+/// so they are declared in this separated trait.
+pub trait EnumGroupMeta: EnumGroup {
+    /// All the group label names of the enum.
+    const GROUPS: &'static [&'static str];
+
+    /// All the variant names of the enum.
+    const VARIANTS: &'static [&'static str];
+
+    /// The descriptors of all the groups of the enum,
+    /// which have the same type for every enum unlike the inherent `group_descriptors()`.
+    const DESCRIPTORS: &'static [GroupDescriptor];
+}
@@ -0,0 +1,120 @@
+// @author:    olinex
+// @time:      2022/07/26
+
+// self mods
+
+// use other mods
+use enum_group_core::{EnumGroup, EnumGroupMeta, GroupDescriptor};
+
+// use self mods
+
+#[allow(dead_code)]
+#[derive(EnumGroup)]
+enum TestTraitEnum {
+    #[groups(integer, comparable)]
+    I8(i8),
+
+    #[groups(comparable)]
+    Str(String),
+
+    Unknown,
+}
+
+#[allow(dead_code)]
+#[derive(EnumGroup)]
+#[enum_group(describe(visible = "shown to the users"))]
+enum TestOtherTraitEnum {
+    #[groups(visible)]
+    Public,
+
+    Private,
+}
+
+mod reexport {
+    pub use enum_group_core as core;
+}
+
+#[derive(EnumGroup)]
+#[enum_group(crate = reexport::core)]
+enum TestReexportEnum {
+    #[groups(reexported)]
+    Foo,
+}
+
+fn describe(value: &dyn EnumGroup) -> String {
+    format!("{}[{}]", value.variant_name(), value.group_names().join(","))
+}
+
+fn count_groups<T: EnumGroupMeta>() -> usize {
+    T::GROUPS.len()
+}
+
+#[test]
+fn test_trait_object() {
+    let values: Vec<Box<dyn EnumGroup>> = vec![
+        Box::new(TestTraitEnum::Str(String::new())),
+        Box::new(TestTraitEnum::Unknown),
+        Box::new(TestOtherTraitEnum::Public),
+        Box::new(TestReexportEnum::Foo),
+    ];
+    let descriptions: Vec<_> = values.iter().map(|value| describe(value.as_ref())).collect();
+    assert_eq!(
+        descriptions,
+        vec!["Str[comparable]", "Unknown[]", "Public[visible]", "Foo[reexported]"]
+    );
+}
+
+#[test]
+fn test_in_group_named() {
+    let value: &dyn EnumGroup = &TestTraitEnum::I8(0);
+    assert_eq!(value.in_group_named("integer"), Some(true));
+    assert_eq!(value.in_group_named("visible"), None);
+    assert_eq!(TestOtherTraitEnum::Private.in_group_named("visible"), Some(false));
+}
+
+#[test]
+fn test_meta() {
+    assert_eq!(count_groups::<TestTraitEnum>(), 2);
+    assert_eq!(TestTraitEnum::VARIANTS, &["I8", "Str", "Unknown"]);
+    assert_eq!(<TestOtherTraitEnum as EnumGroupMeta>::GROUPS, &["visible"]);
+    assert_eq!(TestReexportEnum::VARIANTS, &["Foo"]);
+}
+
+#[test]
+fn test_meta_descriptors() {
+    let descriptors: Vec<GroupDescriptor> = TestTraitEnum::DESCRIPTORS
+        .iter()
+        .chain(TestOtherTraitEnum::DESCRIPTORS)
+        .copied()
+        .collect();
+    let names: Vec<_> = descriptors.iter().map(|descriptor| descriptor.name).collect();
+    assert_eq!(names, vec!["integer", "comparable", "visible"]);
+    assert_eq!(descriptors[1].variants, &["I8", "Str"]);
+    assert_eq!(
+        descriptors[2],
+        GroupDescriptor {
+            name: "visible",
+            description: Some("shown to the users"),
+            variants: &["Public"],
+        }
+    );
+    assert_eq!(TestTraitEnum::group_descriptors()[0].name, "integer");
+}
+
+#[test]
+fn test_inherent_functions() {
+    assert!(TestTraitEnum::I8(0).is_integer());
+    assert!(TestTraitEnum::I8(0).in_group(TestTraitEnumGroup::Comparable));
+}
+
+#[derive(EnumGroup)]
+#[enum_group(skip(variant_name))]
+enum TestSkipTraitEnum {
+    Foo,
+}
+
+#[test]
+fn test_skip_variant_name() {
+    let value: &dyn EnumGroup = &TestSkipTraitEnum::Foo;
+    assert_eq!(value.variant_name(), "Foo");
+}
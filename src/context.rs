@@ -10,43 +10,141 @@ use std::vec::Vec;
 use syn::{punctuated, spanned::Spanned, token};
 
 // use self mods
+use crate::errors::Errors;
+use crate::options::{EnumGroupOptions, EnumGroupVariantOptions, GroupExpr, PredicateCase};
 
 type Variants = punctuated::Punctuated<syn::Variant, token::Comma>;
 type MetaNested = punctuated::Punctuated<syn::NestedMeta, token::Comma>;
+type LabelPath = Vec<syn::Ident>;
+
+// The keywords which cannot be used as raw idents
+const RESERVED_NAMES: [&str; 4] = ["self", "super", "crate", "Self"];
+
+// A group label name with its associated variants and the label paths declaring it
+struct Group {
+    label: syn::Ident,
+    variants: Vec<syn::Variant>,
+    paths: Vec<LabelPath>,
+}
+
+impl Group {
+    // Tell whether the variant belongs to the group
+    fn contains(&self, variant: &syn::Variant) -> bool {
+        self.variants.iter().any(|v| v.ident == variant.ident)
+    }
+}
+
+// Resolve the group definitions into the membership of each variant, detecting cycles and unknown labels
+struct DefineResolver<'b> {
+    groups: &'b [Group],
+    variants: &'b Variants,
+    defines: &'b [(syn::Ident, GroupExpr)],
+    resolved: Vec<Option<Vec<bool>>>,
+    resolving: Vec<usize>,
+}
+
+impl<'b> DefineResolver<'b> {
+    // Resolve the membership of each variant of the group expression
+    fn resolve_expr(&mut self, expr: &GroupExpr) -> syn::Result<Vec<bool>> {
+        match expr {
+            GroupExpr::Label(label) => {
+                if let Some(group) = self.groups.iter().find(|group| group.label == *label) {
+                    return Ok(self.variants.iter().map(|v| group.contains(v)).collect());
+                }
+                match self.defines.iter().position(|(name, _)| name == label) {
+                    Some(index) if self.resolving.contains(&index) => Err(syn::Error::new(
+                        label.span(),
+                        format!("cycle detected in the definition of group `{}`", label),
+                    )),
+                    Some(index) => self.resolve_define(index),
+                    None => Err(syn::Error::new(
+                        label.span(),
+                        format!("unknown group label `{}` in group definition", label),
+                    )),
+                }
+            }
+            GroupExpr::Any(exprs) => {
+                let mut members = vec![false; self.variants.len()];
+                for expr in exprs.iter() {
+                    let sub_members = self.resolve_expr(expr)?;
+                    members
+                        .iter_mut()
+                        .zip(sub_members)
+                        .for_each(|(m, s)| *m |= s);
+                }
+                Ok(members)
+            }
+            GroupExpr::All(exprs) => {
+                let mut members = vec![true; self.variants.len()];
+                for expr in exprs.iter() {
+                    let sub_members = self.resolve_expr(expr)?;
+                    members
+                        .iter_mut()
+                        .zip(sub_members)
+                        .for_each(|(m, s)| *m &= s);
+                }
+                Ok(members)
+            }
+            GroupExpr::Not(expr) => Ok(self.resolve_expr(expr)?.into_iter().map(|m| !m).collect()),
+        }
+    }
+
+    // Resolve the membership of each variant of the group definition at the index
+    fn resolve_define(&mut self, index: usize) -> syn::Result<Vec<bool>> {
+        if let Some(ref members) = self.resolved[index] {
+            return Ok(members.clone());
+        }
+        self.resolving.push(index);
+        let result = self.resolve_expr(&self.defines[index].1);
+        self.resolving.pop();
+        let members = result?;
+        self.resolved[index] = Some(members.clone());
+        Ok(members)
+    }
+}
 
 pub struct EnumGroupContext<'a> {
-    visible: &'a syn::Visibility,
+    visible: syn::Visibility,
     name: &'a syn::Ident,
+    attrs: &'a [syn::Attribute],
     generics: &'a syn::Generics,
     variants: &'a Variants,
-    groups: HashMap<syn::Ident, Vec<syn::Variant>>,
+    groups: Vec<Group>,
+    options: EnumGroupOptions,
+    variant_options: HashMap<syn::Ident, EnumGroupVariantOptions>,
 }
 
 impl<'a> EnumGroupContext<'a> {
+    // Check the label name ident format
+    fn check_label_ident(ident: &syn::Ident) -> syn::Result<()> {
+        let s = ident.to_string();
+        if s.chars()
+            .any(|c| !(c.is_alphanumeric() || c == '_') || c.is_ascii_uppercase())
+        {
+            return Err(syn::Error::new_spanned(
+                ident,
+                "groups attribute ident can only contain the characters a-z0-9_",
+            ));
+        }
+        if s.starts_with('_') {
+            return Err(syn::Error::new_spanned(
+                ident,
+                "groups attribute ident must starts wtih characters a-z",
+            ));
+        }
+        if s.ends_with('_') {
+            return Err(syn::Error::new_spanned(
+                ident,
+                "groups attribute ident must ends wtih characters a-z",
+            ));
+        }
+        Ok(())
+    }
+
     // Extract the label name ident from path and check the ident format
     fn extract_label_ident(path: &syn::Path) -> syn::Result<syn::Ident> {
         if let Some(i) = path.get_ident() {
-            let s = i.to_string();
-            if s.chars()
-                .any(|c| !(c.is_alphanumeric() || c == '_') || c.is_ascii_uppercase())
-            {
-                return Err(syn::Error::new_spanned(
-                    path,
-                    "groups attribute ident can only contain the characters a-z0-9_",
-                ));
-            }
-            if s.starts_with('_') {
-                return Err(syn::Error::new_spanned(
-                    path,
-                    "groups attribute ident must starts wtih characters a-z",
-                ));
-            }
-            if s.ends_with('_') {
-                return Err(syn::Error::new_spanned(
-                    path,
-                    "groups attribute ident must ends wtih characters a-z",
-                ));
-            }
+            Self::check_label_ident(i)?;
             Ok(i.clone())
         } else {
             Err(syn::Error::new_spanned(
@@ -56,97 +154,417 @@ impl<'a> EnumGroupContext<'a> {
         }
     }
 
-    // Extract the label name idents in the group attribute
-    fn extract_label_idents(nested: MetaNested) -> syn::Result<Vec<syn::Ident>> {
+    // Extract the label name paths in the group attribute, each nested level is a segment of the path
+    fn extract_label_idents(nested: MetaNested, errors: &mut Errors) -> Vec<LabelPath> {
         let mut labels: Vec<_> = Vec::new();
         for nest in nested.into_iter() {
             match nest {
                 syn::NestedMeta::Meta(syn::Meta::Path(path)) => {
-                    let label = Self::extract_label_ident(&path)?;
-                    labels.push(label);
+                    if let Some(label) = errors.ok(Self::extract_label_ident(&path)) {
+                        labels.push(vec![label]);
+                    }
                 }
                 syn::NestedMeta::Meta(syn::Meta::List(syn::MetaList {
                     ref path, nested, ..
                 })) => {
-                    let suffix_ident = Self::extract_label_ident(path)?;
-                    let sub_label_idents = Self::extract_label_idents(nested)?;
-                    for sub_label_ident in sub_label_idents.into_iter() {
-                        let concat_ident = syn::Ident::new(
-                            &format!(
-                                "{}_{}",
-                                suffix_ident.to_string(),
-                                sub_label_ident.to_string()
-                            ),
-                            sub_label_ident.span(),
-                        );
-                        labels.push(concat_ident);
+                    let suffix_ident = errors.ok(Self::extract_label_ident(path));
+                    let sub_label_paths = Self::extract_label_idents(nested, errors);
+                    if let Some(suffix_ident) = suffix_ident {
+                        for sub_label_path in sub_label_paths.into_iter() {
+                            let mut label_path = vec![suffix_ident.clone()];
+                            label_path.extend(sub_label_path);
+                            labels.push(label_path);
+                        }
                     }
                 }
-                _ => {
-                    return Err(syn::Error::new_spanned(
-                        nest,
-                        "unknown item in groups attribute",
-                    ))
-                }
+                _ => errors.push(syn::Error::new_spanned(
+                    nest,
+                    "unknown item in groups attribute",
+                )),
             }
         }
-        Ok(labels)
+        labels
     }
 
     // Extract variants of enumeration types that have the `groups` attribute declared
-    fn extract_nested_meta(attributes: &Vec<syn::Attribute>) -> syn::Result<Option<MetaNested>> {
-        let mut result = None;
+    fn extract_nested_meta(
+        attributes: &[syn::Attribute],
+        errors: &mut Errors,
+    ) -> Option<MetaNested> {
+        let mut result: Option<MetaNested> = None;
         for attribute in attributes.iter() {
-            let meta = attribute.parse_meta()?;
-            let nested = match meta {
-                syn::Meta::List(syn::MetaList {
-                    ref path, nested, ..
-                }) if path.is_ident("groups") => nested,
-                _ => continue,
+            if !attribute.path.is_ident("groups") {
+                continue;
+            }
+            let nested = match errors.ok(attribute.parse_meta()) {
+                Some(syn::Meta::List(syn::MetaList { nested, .. })) => nested,
+                Some(meta) => {
+                    errors.push(syn::Error::new_spanned(
+                        meta,
+                        "groups attribute must be a list like `groups(label1, label2)`",
+                    ));
+                    continue;
+                }
+                None => continue,
             };
             if nested.is_empty() {
-                return Err(syn::Error::new_spanned(
-                    nested,
+                errors.push(syn::Error::new_spanned(
+                    attribute,
                     "must have group ident in groups attribute",
                 ));
+                continue;
             }
-            if result.is_some() {
-                return Err(syn::Error::new_spanned(
-                    attribute,
-                    "duplicate groups attribute",
-                ));
+            result.get_or_insert_with(MetaNested::new).extend(nested);
+        }
+        result
+    }
+
+    // Concat the segments of the label path into the flattened label name ident
+    fn concat_label_path(path: &[syn::Ident]) -> syn::Ident {
+        let names: Vec<_> = path.iter().map(|i| i.to_string()).collect();
+        syn::Ident::new(&names.join("_"), path[path.len() - 1].span())
+    }
+
+    // Add the variant into the group which is declared by the label path
+    fn add_group_variant(
+        groups: &mut Vec<Group>,
+        label_path: &[syn::Ident],
+        variant: &syn::Variant,
+    ) {
+        let ident = Self::concat_label_path(label_path);
+        match groups.iter_mut().find(|group| group.label == ident) {
+            Some(group) => {
+                if !group.contains(variant) {
+                    group.variants.push(variant.clone());
+                }
+                if !group.paths.iter().any(|path| path == label_path) {
+                    group.paths.push(label_path.to_vec());
+                }
             }
-            result = Some(nested);
+            None => groups.push(Group {
+                label: ident,
+                variants: vec![variant.clone()],
+                paths: vec![label_path.to_vec()],
+            }),
         }
-        Ok(result)
     }
 
-    // Extract enumerated group label names and their associated variants
-    fn extract_groups(variants: &Variants) -> syn::Result<HashMap<syn::Ident, Vec<syn::Variant>>> {
-        let mut groups: HashMap<syn::Ident, Vec<syn::Variant>> = HashMap::new();
+    // Extract enumerated group label names with their associated variants and label paths,
+    // the groups are ordered by the first appearance of their label names in the enum,
+    // and the variants of each group are ordered by their declaration.
+    // Each prefix of a nested label path is also a group which contains all of its sub groups
+    fn extract_groups(
+        variants: &Variants,
+        default_labels: &[syn::Ident],
+        vocabulary: Option<&[syn::Ident]>,
+        variant_options: &HashMap<syn::Ident, EnumGroupVariantOptions>,
+        groups: &mut Vec<Group>,
+        errors: &mut Errors,
+    ) {
         for variant in variants.iter() {
-            let meta = Self::extract_nested_meta(&variant.attrs)?;
+            let meta = Self::extract_nested_meta(&variant.attrs, errors);
+            let mut variant_labels: Vec<syn::Ident> = Vec::new();
+            let mut excluded_labels: Vec<syn::Ident> = Vec::new();
             if let Some(nested) = meta {
-                let idents = Self::extract_label_idents(nested)?;
-                for ident in idents.iter() {
-                    if ident.to_string().to_lowercase() == variant.ident.to_string().to_lowercase()
-                    {
-                        return Err(syn::Error::new_spanned(
-                            variant,
-                            "conflict group label name and viriant name. group name cannot equal to variant name",
+                // `not(...)` only opts out of the default groups when there are any,
+                // otherwise it is an ordinary nested label
+                let (excluded_nested, nested): (MetaNested, MetaNested) =
+                    nested.into_iter().partition(|nest| {
+                        !default_labels.is_empty()
+                            && matches!(nest, syn::NestedMeta::Meta(syn::Meta::List(list)) if list.path.is_ident("not"))
+                    });
+                for nest in excluded_nested.into_iter() {
+                    if let syn::NestedMeta::Meta(syn::Meta::List(list)) = nest {
+                        for label_path in Self::extract_label_idents(list.nested, errors) {
+                            let ident = Self::concat_label_path(&label_path);
+                            if !default_labels.contains(&ident) {
+                                errors.push(syn::Error::new(
+                                    ident.span(),
+                                    format!("group label `{}` is not a default group", ident),
+                                ));
+                            }
+                            excluded_labels.push(ident);
+                        }
+                    }
+                }
+                let label_paths = Self::extract_label_idents(nested, errors);
+                for label_path in label_paths.into_iter() {
+                    let ident = Self::concat_label_path(&label_path);
+                    if variant_labels.contains(&ident) {
+                        errors.push(syn::Error::new(
+                            ident.span(),
+                            format!("duplicate group label `{}` in groups attribute", ident),
                         ));
+                        continue;
                     }
-                    match groups.get_mut(ident) {
-                        Some(items) => items.push(variant.clone()),
-                        None => {
-                            let items = vec![variant.clone()];
-                            groups.insert(ident.clone(), items);
-                        }
+                    if excluded_labels.contains(&ident) {
+                        errors.push(syn::Error::new(
+                            ident.span(),
+                            format!("group label `{}` is both declared and excluded", ident),
+                        ));
+                        continue;
+                    }
+                    if let Err(e) = Self::check_declared_label(&ident, vocabulary) {
+                        errors.push(e);
+                        continue;
+                    }
+                    variant_labels.push(ident);
+                    for end in 1..=label_path.len() {
+                        Self::add_group_variant(groups, &label_path[..end], variant);
+                    }
+                }
+            }
+            let skip_default = variant_options
+                .get(&variant.ident)
+                .is_some_and(|options| options.skip_default);
+            if !skip_default {
+                for label in default_labels.iter() {
+                    if !excluded_labels.contains(label) {
+                        Self::add_group_variant(groups, std::slice::from_ref(label), variant);
                     }
                 }
             }
         }
-        Ok(groups)
+        for group in groups.iter_mut() {
+            group
+                .variants
+                .sort_by_key(|v| variants.iter().position(|variant| variant.ident == v.ident));
+        }
+    }
+
+    // Check that the group label belongs to the vocabulary declared by the labels option, if any
+    fn check_declared_label(
+        label: &syn::Ident,
+        vocabulary: Option<&[syn::Ident]>,
+    ) -> syn::Result<()> {
+        if vocabulary.is_some_and(|labels| !labels.contains(label)) {
+            Err(syn::Error::new(
+                label.span(),
+                format!("group label `{}` is not declared in labels option", label),
+            ))
+        } else {
+            Ok(())
+        }
+    }
+
+    // Extract the groups declared on the enum by their labels only, which may have no variants
+    fn extract_declared_groups(
+        labels: &[syn::Ident],
+        option: &str,
+        groups: &mut Vec<Group>,
+        errors: &mut Errors,
+    ) {
+        for (index, label) in labels.iter().enumerate() {
+            if let Err(e) = Self::check_label_ident(label) {
+                errors.push(e);
+            } else if labels[..index].contains(label) {
+                errors.push(syn::Error::new(
+                    label.span(),
+                    format!("duplicate group label `{}` in {} option", label, option),
+                ));
+            } else if !groups.iter().any(|group| group.label == *label) {
+                groups.push(Group {
+                    label: label.clone(),
+                    variants: Vec::new(),
+                    paths: vec![vec![label.clone()]],
+                });
+            }
+        }
+    }
+
+    // Extract the groups declared on the enum by the lists of their variant names
+    fn extract_listed_groups(
+        variants: &Variants,
+        listed_groups: &[(syn::Ident, Vec<syn::Ident>)],
+        errors: &mut Errors,
+    ) -> Vec<Group> {
+        let mut groups: Vec<Group> = Vec::new();
+        for (label, variant_idents) in listed_groups.iter() {
+            if let Err(e) = Self::check_label_ident(label) {
+                errors.push(e);
+                continue;
+            }
+            if groups.iter().any(|group| group.label == *label) {
+                errors.push(syn::Error::new(
+                    label.span(),
+                    format!("duplicate group label `{}` in group option", label),
+                ));
+                continue;
+            }
+            let mut group = Group {
+                label: label.clone(),
+                variants: Vec::new(),
+                paths: vec![vec![label.clone()]],
+            };
+            for ident in variant_idents.iter() {
+                match variants.iter().find(|variant| variant.ident == *ident) {
+                    Some(variant) if group.contains(variant) => errors.push(syn::Error::new(
+                        ident.span(),
+                        format!("duplicate variant `{}` in group `{}`", ident, label),
+                    )),
+                    Some(variant) => group.variants.push(variant.clone()),
+                    None => errors.push(syn::Error::new(
+                        ident.span(),
+                        format!("unknown variant `{}` in group `{}`", ident, label),
+                    )),
+                }
+            }
+            groups.push(group);
+        }
+        groups
+    }
+
+    // Extract the groups defined by the group expressions of the enum options in the order of definition
+    fn extract_defined_groups(
+        variants: &Variants,
+        groups: &[Group],
+        defines: &[(syn::Ident, GroupExpr)],
+        errors: &mut Errors,
+    ) -> Vec<Group> {
+        let mut resolver = DefineResolver {
+            groups,
+            variants,
+            defines,
+            resolved: vec![None; defines.len()],
+            resolving: Vec::new(),
+        };
+        let mut defined_groups: Vec<Group> = Vec::new();
+        for (index, (name, _)) in defines.iter().enumerate() {
+            if let Err(e) = Self::check_label_ident(name) {
+                errors.push(e);
+                continue;
+            }
+            if groups.iter().any(|group| group.label == *name)
+                || defines[..index].iter().any(|(n, _)| n == name)
+            {
+                errors.push(syn::Error::new(
+                    name.span(),
+                    format!("duplicate group label `{}` in group definition", name),
+                ));
+                continue;
+            }
+            if let Some(members) = errors.ok(resolver.resolve_define(index)) {
+                defined_groups.push(Group {
+                    label: name.clone(),
+                    variants: variants
+                        .iter()
+                        .zip(members)
+                        .filter(|(_, member)| *member)
+                        .map(|(variant, _)| variant.clone())
+                        .collect(),
+                    paths: vec![vec![name.clone()]],
+                });
+            }
+        }
+        defined_groups
+    }
+
+    // Sort the groups by where their labels first appear, the labels declared by the enum options
+    // come first in the order of declaration, then the labels declared by the variants
+    fn sort_groups(groups: &mut Vec<Group>, label_order: &[syn::Ident]) {
+        let mut keyed_groups: Vec<_> = std::mem::take(groups)
+            .into_iter()
+            .enumerate()
+            .map(|(index, group)| {
+                let key = label_order
+                    .iter()
+                    .position(|label| *label == group.label)
+                    .unwrap_or(label_order.len() + index);
+                (key, group)
+            })
+            .collect();
+        keyed_groups.sort_by_key(|(key, _)| *key);
+        groups.extend(keyed_groups.into_iter().map(|(_, group)| group));
+    }
+
+    // Check that each description is given once for an existing group
+    fn check_descriptions(
+        groups: &[Group],
+        descriptions: &[(syn::Ident, syn::LitStr)],
+        errors: &mut Errors,
+    ) {
+        for (index, (label, _)) in descriptions.iter().enumerate() {
+            if !groups.iter().any(|group| group.label == *label) {
+                errors.push(syn::Error::new(
+                    label.span(),
+                    format!("cannot describe unknown group label `{}`", label),
+                ));
+            } else if descriptions[..index].iter().any(|(l, _)| l == label) {
+                errors.push(syn::Error::new(
+                    label.span(),
+                    format!("duplicate description of group label `{}`", label),
+                ));
+            }
+        }
+    }
+
+    // Check that each visibility override is given once for an existing group
+    fn check_label_visibilities(
+        groups: &[Group],
+        label_vis: &[(syn::Ident, syn::Visibility)],
+        errors: &mut Errors,
+    ) {
+        for (index, (label, _)) in label_vis.iter().enumerate() {
+            if !groups.iter().any(|group| group.label == *label) {
+                errors.push(syn::Error::new(
+                    label.span(),
+                    format!("cannot set visibility of unknown group label `{}`", label),
+                ));
+            } else if label_vis[..index].iter().any(|(l, _)| l == label) {
+                errors.push(syn::Error::new(
+                    label.span(),
+                    format!("duplicate visibility of group label `{}`", label),
+                ));
+            }
+        }
+    }
+
+    // Check that each trait is bound once to an existing group
+    fn check_impl_traits(
+        groups: &[Group],
+        impl_traits: &[(syn::Ident, syn::Path)],
+        errors: &mut Errors,
+    ) {
+        for (index, (label, path)) in impl_traits.iter().enumerate() {
+            if !groups.iter().any(|group| group.label == *label) {
+                errors.push(syn::Error::new(
+                    label.span(),
+                    format!("cannot implement trait for unknown group label `{}`", label),
+                ));
+            } else if impl_traits[..index].iter().any(|(l, _)| l == label) {
+                errors.push(syn::Error::new(
+                    label.span(),
+                    format!("duplicate trait of group label `{}`", label),
+                ));
+            } else if impl_traits[..index].iter().any(|(_, p)| p == path) {
+                errors.push(syn::Error::new_spanned(
+                    path,
+                    "trait is already bound to another group label",
+                ));
+            }
+        }
+    }
+
+    // Check that each subset enum mirrors an existing group of a non-generic enum
+    fn check_subsets(
+        generics: &syn::Generics,
+        groups: &[Group],
+        subsets: &[(syn::Ident, syn::Ident)],
+        errors: &mut Errors,
+    ) {
+        for (label, name) in subsets.iter() {
+            if !generics.params.is_empty() {
+                errors.push(syn::Error::new(
+                    name.span(),
+                    "subset option is not supported for generic enum",
+                ));
+            } else if !groups.iter().any(|group| group.label == *label) {
+                errors.push(syn::Error::new(
+                    label.span(),
+                    format!("cannot generate subset of unknown group label `{}`", label),
+                ));
+            }
+        }
     }
 
     // Extract each variant declaration of the enum
@@ -154,74 +572,383 @@ impl<'a> EnumGroupContext<'a> {
         if let syn::Data::Enum(syn::DataEnum { ref variants, .. }) = input.data {
             Ok(variants)
         } else {
-            return Err(syn::Error::new_spanned(input, "must define on enum"));
+            Err(syn::Error::new_spanned(input, "must define on enum"))
         }
     }
 
     pub fn new(input: &'a syn::DeriveInput) -> syn::Result<Self> {
-        let visible = &input.vis;
         let name = &input.ident;
         let generics = &input.generics;
         let variants = Self::extract_variants(input)?;
-        let groups = Self::extract_groups(variants)?;
-        Ok(Self {
+        let mut errors = Errors::default();
+        let options = errors.ok(EnumGroupOptions::new(&input.attrs));
+        let variant_options: HashMap<_, _> = variants
+            .iter()
+            .filter_map(|v| {
+                let options = errors.ok(EnumGroupVariantOptions::new(&v.attrs))?;
+                Some((v.ident.clone(), options))
+            })
+            .collect();
+        let (listed_groups, default_labels, vocabulary) = match options {
+            Some(ref options) => (
+                &options.listed_groups[..],
+                &options.default_groups[..],
+                options.labels.as_deref(),
+            ),
+            None => (&[][..], &[][..], None),
+        };
+        for label in default_labels
+            .iter()
+            .chain(listed_groups.iter().map(|(label, _)| label))
+        {
+            if let Err(e) = Self::check_declared_label(label, vocabulary) {
+                errors.push(e);
+            }
+        }
+        let mut groups = Self::extract_listed_groups(variants, listed_groups, &mut errors);
+        Self::extract_declared_groups(default_labels, "default_groups", &mut groups, &mut errors);
+        if let Some(labels) = vocabulary {
+            Self::extract_declared_groups(labels, "labels", &mut groups, &mut errors);
+        }
+        Self::extract_groups(
+            variants,
+            default_labels,
+            vocabulary,
+            &variant_options,
+            &mut groups,
+            &mut errors,
+        );
+        if let Some(ref options) = options {
+            let defined_groups =
+                Self::extract_defined_groups(variants, &groups, &options.defines, &mut errors);
+            groups.extend(defined_groups);
+            Self::sort_groups(&mut groups, &options.label_order);
+            Self::check_descriptions(&groups, &options.descriptions, &mut errors);
+            Self::check_label_visibilities(&groups, &options.label_vis, &mut errors);
+            Self::check_impl_traits(&groups, &options.impl_traits, &mut errors);
+            Self::check_subsets(generics, &groups, &options.subsets, &mut errors);
+        }
+        // The generated names depend on the enum options, so the collisions can only be checked with them
+        let options = match options {
+            Some(options) => options,
+            None => {
+                errors.finish()?;
+                unreachable!("enum options must exist without errors");
+            }
+        };
+        let visible = options.vis.clone().unwrap_or_else(|| input.vis.clone());
+        let ctx = Self {
             visible,
             name,
+            attrs: &input.attrs,
             groups,
+            options,
+            variant_options,
             generics,
             variants,
-        })
+        };
+        ctx.check_collisions(&mut errors);
+        errors.finish()?;
+        Ok(ctx)
+    }
+
+    // Check that the generated names are unique, the first declaration of each name is remembered
+    // so that the error can point at both declarations
+    fn check_unique_names(names: Vec<(syn::Ident, String)>, kind: &str, errors: &mut Errors) {
+        let mut declared: HashMap<String, (syn::Ident, String)> = HashMap::new();
+        for (ident, source) in names.into_iter() {
+            let name = ident.to_string();
+            match declared.get(&name) {
+                Some((first_ident, first_source)) => {
+                    let mut e = syn::Error::new(
+                        ident.span(),
+                        format!(
+                            "conflict {} `{}` generated by {}, which is already generated by {}",
+                            kind, name, source, first_source
+                        ),
+                    );
+                    e.combine(syn::Error::new(
+                        first_ident.span(),
+                        format!(
+                            "{} `{}` is first generated by {} here",
+                            kind, name, first_source
+                        ),
+                    ));
+                    errors.push(e);
+                }
+                None => {
+                    declared.insert(name, (ident, source));
+                }
+            }
+        }
+    }
+
+    // Check that none of the generated names is a keyword which cannot be written as a raw ident
+    fn check_reserved_names(names: &[(syn::Ident, String)], kind: &str, errors: &mut Errors) {
+        for (ident, source) in names.iter() {
+            let name = ident.to_string();
+            if RESERVED_NAMES.contains(&name.as_str()) {
+                errors.push(syn::Error::new(
+                    ident.span(),
+                    format!(
+                        "{} `{}` generated by {} is a reserved keyword",
+                        kind, name, source
+                    ),
+                ));
+            }
+        }
+    }
+
+    // Check that all the generated function names and group label enum variant names are unique
+    fn check_collisions(&self, errors: &mut Errors) {
+        let call_site = proc_macro2::Span::call_site();
+        let mut fn_names = Vec::new();
+        let fixed_fn_names = vec![
+            "groups",
+            "in_group",
+            "group_set",
+            "group_names",
+            "is_in_group_named",
+            "group_descriptors",
+        ];
+        if !self.options.skip_variant_name {
+            fn_names.push((
+                self.options.variant_name(),
+                "the variant name function".to_string(),
+            ));
+        }
+        for name in fixed_fn_names.into_iter() {
+            fn_names.push((
+                syn::Ident::new(name, call_site),
+                format!("the `{}` function", name),
+            ));
+        }
+        if !self.options.skip_variant_predicates {
+            for variant in self.variants.iter() {
+                fn_names.push((
+                    self.gen_variant_label_ident(variant),
+                    format!("variant `{}`", variant.ident),
+                ));
+            }
+        }
+        for group in self.groups.iter() {
+            fn_names.push((
+                self.gen_group_label_ident(&group.label),
+                format!("group label `{}`", group.label),
+            ));
+        }
+        let group_variant_names: Vec<_> = self
+            .groups
+            .iter()
+            .map(|group| {
+                (
+                    Self::gen_group_variant_ident(&group.label),
+                    format!("group label `{}`", group.label),
+                )
+            })
+            .collect();
+        let mut type_names = vec![
+            (
+                self.gen_group_enum_ident(),
+                "the group label enum".to_string(),
+            ),
+            (self.gen_group_set_ident(), "the group set".to_string()),
+            (
+                self.gen_group_descriptor_ident(),
+                "the group descriptor".to_string(),
+            ),
+        ];
+        if let Some(ref trait_name) = self.options.trait_name {
+            type_names.push((trait_name.clone(), "the group trait".to_string()));
+        }
+        for (label, subset_name) in self.options.subsets.iter() {
+            type_names.push((
+                subset_name.clone(),
+                format!("subset of group label `{}`", label),
+            ));
+        }
+        type_names.push((
+            self.gen_group_marker_mod_ident(),
+            "the group marker module".to_string(),
+        ));
+        let marker_trait_ident = syn::Ident::new("GroupOf", call_site);
+        let mut marker_names = vec![(
+            marker_trait_ident.clone(),
+            "the group marker trait".to_string(),
+        )];
+        marker_names.extend(
+            self.groups
+                .iter()
+                .filter(|group| Self::gen_group_variant_ident(&group.label) == marker_trait_ident)
+                .map(|group| {
+                    (
+                        marker_trait_ident.clone(),
+                        format!("group label `{}`", group.label),
+                    )
+                }),
+        );
+        if self.options.namespace {
+            for path in self.collect_namespace_paths().iter() {
+                let names: Vec<_> = path.iter().map(|i| i.to_string()).collect();
+                let source = format!("namespace `{}`", names.join("."));
+                let segment = &path[path.len() - 1];
+                Self::check_reserved_names(
+                    &[(segment.clone(), source.clone())],
+                    "function name",
+                    errors,
+                );
+                if path.len() == 1 {
+                    fn_names.push((Self::gen_namespace_segment_ident(segment), source.clone()));
+                }
+                type_names.push((self.gen_namespace_ident(path), source));
+            }
+        }
+        Self::check_unique_names(fn_names, "function name", errors);
+        Self::check_reserved_names(&group_variant_names, "group enum variant", errors);
+        Self::check_unique_names(group_variant_names, "group enum variant", errors);
+        Self::check_unique_names(type_names, "type name", errors);
+        Self::check_unique_names(marker_names, "group marker type", errors);
+    }
+
+    // Convert the camel case variant name into the snake case name, e.g. `HTTPError` into `http_error`
+    fn to_snake_case(name: &str) -> String {
+        let chars: Vec<_> = name.chars().collect();
+        let mut snake = String::new();
+        for (i, c) in chars.iter().enumerate() {
+            if *c == '_' {
+                if !snake.is_empty() && !snake.ends_with('_') {
+                    snake.push('_');
+                }
+                continue;
+            }
+            if c.is_uppercase() && i > 0 {
+                let prev = chars[i - 1];
+                let next_is_lowercase = chars.get(i + 1).is_some_and(|n| n.is_lowercase());
+                if (prev.is_lowercase()
+                    || prev.is_ascii_digit()
+                    || (prev.is_uppercase() && next_is_lowercase))
+                    && !snake.ends_with('_')
+                {
+                    snake.push('_');
+                }
+            }
+            snake.extend(c.to_lowercase());
+        }
+        snake
     }
 
-    // Generate a new ident by prefixing the group label name with "is_"
-    fn gen_group_label_ident(label: &syn::Ident) -> syn::Ident {
+    // Generate a new ident by adding the prefix and the suffix to the name
+    fn gen_predicate_ident(&self, name: &str, span: proc_macro2::Span) -> syn::Ident {
         syn::Ident::new(
-            &format!("is_{}", label.to_string().to_lowercase()),
-            label.span(),
+            &format!("{}{}{}", self.options.prefix(), name, self.options.suffix()),
+            span,
         )
     }
 
+    // Generate a new ident by adding the prefix ("is_" by default) and the suffix to the group label name
+    fn gen_group_label_ident(&self, label: &syn::Ident) -> syn::Ident {
+        self.gen_predicate_ident(&label.to_string().to_lowercase(), label.span())
+    }
+
+    // Generate a new ident by adding the prefix and the suffix to the variant name in the case of the options,
+    // unless the variant declares its own predicate name
+    fn gen_variant_label_ident(&self, variant: &syn::Variant) -> syn::Ident {
+        let predicate = self
+            .variant_options
+            .get(&variant.ident)
+            .and_then(|options| options.predicate.as_ref());
+        if let Some(predicate) = predicate {
+            return predicate.clone();
+        }
+        let name = variant.ident.to_string();
+        let name = match self.options.predicate_case() {
+            PredicateCase::Lower => name.to_lowercase(),
+            PredicateCase::Snake => Self::to_snake_case(&name),
+        };
+        self.gen_predicate_ident(&name, variant.ident.span())
+    }
+
+    // Generate the ident of the group label enum by suffixing the enum name with "Group"
+    fn gen_group_enum_ident(&self) -> syn::Ident {
+        syn::Ident::new(&format!("{}Group", self.name), self.name.span())
+    }
+
+    // Generate the variant ident of the group label enum by converting the label name to camel case
+    fn gen_group_variant_ident(label: &syn::Ident) -> syn::Ident {
+        let camel: String = label
+            .to_string()
+            .split('_')
+            .filter(|s| !s.is_empty())
+            .map(|s| {
+                let mut chars = s.chars();
+                match chars.next() {
+                    Some(c) => c.to_uppercase().chain(chars).collect(),
+                    None => String::new(),
+                }
+            })
+            .collect();
+        syn::Ident::new(&camel, label.span())
+    }
+
+    // Collect the cfg attributes of the variant, which must also guard everything generated for it
+    fn gen_variant_cfgs(variant: &syn::Variant) -> Vec<&syn::Attribute> {
+        variant
+            .attrs
+            .iter()
+            .filter(|attr| attr.path.is_ident("cfg"))
+            .collect()
+    }
+
     // Generate different arms based on three different variant patterns of the enum type
     fn gen_variant_arm(variant: &syn::Variant) -> proc_macro2::TokenStream {
         let name = &variant.ident;
+        let cfgs = Self::gen_variant_cfgs(variant);
         match variant.fields {
             syn::Fields::Named(_) => {
                 quote!(
-                    #name{..}
+                    #(#cfgs)* Self::#name{..}
                 )
             }
             syn::Fields::Unnamed(_) => {
                 quote!(
-                    #name(..)
+                    #(#cfgs)* Self::#name(..)
                 )
             }
             syn::Fields::Unit => {
                 quote!(
-                    #name
+                    #(#cfgs)* Self::#name
                 )
             }
         }
     }
 
-    // Generate a function that returns the name of each variant
-    fn gen_variant_name_fn_expr(&self) -> proc_macro2::TokenStream {
-        let visible = self.visible;
-        let variant_arms: Vec<_> = self
-            .variants
-            .iter()
-            .map(|v| Self::gen_variant_arm(v))
-            .collect();
+    // Generate a match expression that returns the name of each variant
+    fn gen_variant_name_match_expr(&self) -> proc_macro2::TokenStream {
+        let variant_arms: Vec<_> = self.variants.iter().map(Self::gen_variant_arm).collect();
         let variant_names: Vec<_> = self
             .variants
             .iter()
             .map(|v| syn::LitStr::new(&v.ident.to_string(), v.span()))
             .collect();
+        quote!(
+            match self {
+                #(#variant_arms => #variant_names),*
+            }
+        )
+    }
+
+    // Generate a function that returns the name of each variant
+    fn gen_variant_name_fn_expr(&self) -> proc_macro2::TokenStream {
+        if self.options.skip_variant_name {
+            return quote!();
+        }
+        let visible = &self.visible;
+        let variant_name_ident = self.options.variant_name();
+        let variant_name_match_expr = self.gen_variant_name_match_expr();
         quote!(
             #[inline]
-            #visible fn variant_name(&self) -> &str {
-                match self {
-                    #(Self::#variant_arms => #variant_names),*
-                }
+            #visible fn #variant_name_ident(&self) -> &'static str {
+                #variant_name_match_expr
             }
         )
     }
@@ -229,53 +956,1212 @@ impl<'a> EnumGroupContext<'a> {
     // Generate a judgment function for a group label name
     fn gen_group_fn_expr(
         &self,
-        label: &syn::Ident,
-        variants: &Vec<syn::Variant>,
+        attrs: proc_macro2::TokenStream,
+        visible: &syn::Visibility,
+        label_ident: &syn::Ident,
+        variants: &[syn::Variant],
     ) -> proc_macro2::TokenStream {
-        let visible = self.visible;
-        let label_ident = Self::gen_group_label_ident(label);
-        let variant_arms: Vec<_> = variants.iter().map(|v| Self::gen_variant_arm(v)).collect();
+        let variant_arms: Vec<_> = variants.iter().map(Self::gen_variant_arm).collect();
+        if variant_arms.is_empty() {
+            return quote!(
+                #attrs
+                #[inline]
+                #visible fn #label_ident(&self) -> bool {
+                    false
+                }
+            );
+        }
         quote!(
+            #attrs
             #[inline]
             #visible fn #label_ident(&self) -> bool {
                 match self {
-                    #(Self::#variant_arms)|* => true,
+                    #(#variant_arms => true,)*
                     _ => false
                 }
             }
         )
     }
 
+    // Generate the doc comment of the judgment function for a group, which lists its variants
+    fn gen_group_fn_doc(&self, group: &Group) -> proc_macro2::TokenStream {
+        let members = if group.variants.is_empty() {
+            "no variant".to_string()
+        } else {
+            group
+                .variants
+                .iter()
+                .map(|v| format!("`{}`", v.ident))
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        let doc = format!(
+            " Returns `true` if the variant belongs to the group `{}`, which contains {}.",
+            group.label, members
+        );
+        match self.gen_group_description(&group.label) {
+            Some(description) => {
+                let description = format!(" {}", description.value());
+                quote!(#[doc = #description] #[doc = ""] #[doc = #doc])
+            }
+            None => quote!(#[doc = #doc]),
+        }
+    }
+
+    // Iterate over the groups shared by the aggregate items at the enum-wide visibility,
+    // which leave out the group labels with their own visibility
+    fn shared_groups(&self) -> impl Iterator<Item = &Group> {
+        self.groups.iter().filter(|group| {
+            !self
+                .options
+                .label_vis
+                .iter()
+                .any(|(label, _)| *label == group.label)
+        })
+    }
+
+    // Find the visibility of the items generated for the group label, which may be overridden by the vis option
+    fn gen_group_visible(&self, label: &syn::Ident) -> &syn::Visibility {
+        self.options
+            .label_vis
+            .iter()
+            .find(|(l, _)| l == label)
+            .map_or(&self.visible, |(_, visible)| visible)
+    }
+
+    // Find the description of the group label given by the describe option
+    fn gen_group_description(&self, label: &syn::Ident) -> Option<&syn::LitStr> {
+        self.options
+            .descriptions
+            .iter()
+            .find(|(l, _)| l == label)
+            .map(|(_, description)| description)
+    }
+
+    // Generate the attributes of the judgment function for a variant from its own docs and deprecation
+    fn gen_variant_fn_attrs(variant: &syn::Variant) -> proc_macro2::TokenStream {
+        let docs: Vec<_> = variant
+            .attrs
+            .iter()
+            .filter(|attr| attr.path.is_ident("doc"))
+            .collect();
+        let deprecations = variant
+            .attrs
+            .iter()
+            .filter(|attr| attr.path.is_ident("deprecated"));
+        if docs.is_empty() {
+            let doc = format!(
+                " Returns `true` if the value is the variant `{}`.",
+                variant.ident
+            );
+            quote!(#[doc = #doc] #(#deprecations)*)
+        } else {
+            quote!(#(#docs)* #(#deprecations)*)
+        }
+    }
+
     // Generate a corresponding judgment function for each group label name
     fn gen_group_fn_exprs(&self) -> Vec<proc_macro2::TokenStream> {
         self.groups
             .iter()
-            .map(|(label, variants)| self.gen_group_fn_expr(label, variants))
+            .map(|group| {
+                if self.options.trait_name.is_some() {
+                    self.gen_group_fn_expr(
+                        quote!(),
+                        &syn::Visibility::Inherited,
+                        &self.gen_group_label_ident(&group.label),
+                        &group.variants,
+                    )
+                } else {
+                    self.gen_group_fn_expr(
+                        self.gen_group_fn_doc(group),
+                        self.gen_group_visible(&group.label),
+                        &self.gen_group_label_ident(&group.label),
+                        &group.variants,
+                    )
+                }
+            })
             .collect()
     }
 
-    // Generate a serial of judgment functions for a group label name
-    fn gen_variant_group_fn_exprs(&self) -> Vec<proc_macro2::TokenStream> {
-        self.variants
-            .iter()
-            .map(|variant| self.gen_group_fn_expr(&variant.ident, &vec![variant.clone()]))
-            .collect()
+    // Generate the expression which calls the judgment function of the group label on the receiver
+    fn gen_group_call_expr(
+        &self,
+        label: &syn::Ident,
+        receiver: proc_macro2::TokenStream,
+    ) -> proc_macro2::TokenStream {
+        let label_ident = self.gen_group_label_ident(label);
+        match self.options.trait_name {
+            Some(ref trait_name) => quote!(#trait_name::#label_ident(#receiver)),
+            None => quote!(#receiver.#label_ident()),
+        }
     }
 
-    // Generate a trait inheritance declaration that includes a group label name judgment function
-    pub fn generate(&self) -> syn::Result<proc_macro2::TokenStream> {
-        let name = self.name;
-        let generics = self.generics;
-        let group_fn_exprs = self.gen_group_fn_exprs();
-        let variant_group_fn_exprs = self.gen_variant_group_fn_exprs();
-        let variant_name_fn_expr = self.gen_variant_name_fn_expr();
-        let st = quote!(
-            impl #generics #name #generics {
-                #(#group_fn_exprs)*
+    fn gen_group_marker_mod_ident(&self) -> syn::Ident {
+        syn::Ident::new(
+            &format!("{}_groups", Self::to_snake_case(&self.name.to_string())),
+            self.name.span(),
+        )
+    }
 
-                #(#variant_group_fn_exprs)*
+    // Generate the visibility inside a child module which is the same as the visibility in the current module
+    fn gen_nested_visible(visible: &syn::Visibility) -> proc_macro2::TokenStream {
+        match visible {
+            syn::Visibility::Inherited => quote!(pub(super)),
+            syn::Visibility::Restricted(restricted) => {
+                let path = &restricted.path;
+                let mut segments = path.segments.iter();
+                match segments.next() {
+                    Some(first) if path.leading_colon.is_none() && first.ident == "crate" => {
+                        quote!(#visible)
+                    }
+                    Some(first) if first.ident == "self" => {
+                        let rest: Vec<_> = segments.collect();
+                        quote!(pub(in super #(::#rest)*))
+                    }
+                    _ => quote!(pub(in super::#path)),
+                }
+            }
+            _ => quote!(#visible),
+        }
+    }
 
-                #variant_name_fn_expr
+    // Generate a module which contains a zero-sized marker type for each group label name,
+    // and the trait which tests the variants against the group of the marker type
+    fn gen_group_marker_exprs(&self) -> proc_macro2::TokenStream {
+        let visible = &self.visible;
+        let name = self.name;
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+        let mod_ident = self.gen_group_marker_mod_ident();
+        let mod_doc = format!(" The marker types of the group label names of `{}`.", name);
+        let marker_idents: Vec<_> = self
+            .groups
+            .iter()
+            .map(|group| Self::gen_group_variant_ident(&group.label))
+            .collect();
+        let marker_docs: Vec<_> = self
+            .groups
+            .iter()
+            .map(|group| format!(" The marker type of the group `{}`.", group.label))
+            .collect();
+        let marker_visibles: Vec<_> = self
+            .groups
+            .iter()
+            .map(|group| Self::gen_nested_visible(self.gen_group_visible(&group.label)))
+            .collect();
+        let group_names: Vec<_> = self
+            .groups
+            .iter()
+            .map(|group| syn::LitStr::new(&group.label.to_string(), group.label.span()))
+            .collect();
+        let group_calls: Vec<_> = self
+            .groups
+            .iter()
+            .map(|group| self.gen_group_call_expr(&group.label, quote!(v)))
+            .collect();
+        quote!(
+            #[doc = #mod_doc]
+            #[allow(dead_code)]
+            #visible mod #mod_ident {
+                /// Tests whether the variants of the enum belong to the group of the marker type.
+                pub trait GroupOf<E: ?Sized> {
+                    const NAME: &'static str;
+
+                    fn test(v: &E) -> bool;
+                }
+
+                #(
+                    #[doc = #marker_docs]
+                    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+                    #marker_visibles struct #marker_idents;
+                )*
+            }
+
+            #(
+                impl #impl_generics #mod_ident::GroupOf<#name #ty_generics> for #mod_ident::#marker_idents #where_clause {
+                    const NAME: &'static str = #group_names;
+
+                    #[inline]
+                    fn test(v: &#name #ty_generics) -> bool {
+                        #group_calls
+                    }
+                }
+            )*
+        )
+    }
+
+    // Keep the attributes which still make sense on the copies of the variants and fields
+    fn gen_passthrough_attrs(attrs: &[syn::Attribute]) -> Vec<&syn::Attribute> {
+        attrs
+            .iter()
+            .filter(|attr| {
+                ["doc", "cfg", "deprecated", "non_exhaustive"]
+                    .iter()
+                    .any(|name| attr.path.is_ident(name))
+            })
+            .collect()
+    }
+
+    // Generate the pattern which binds all the fields of the variant, which also constructs it
+    fn gen_variant_bindings(variant: &syn::Variant) -> proc_macro2::TokenStream {
+        let name = &variant.ident;
+        match variant.fields {
+            syn::Fields::Named(ref fields) => {
+                let field_idents = fields.named.iter().map(|field| &field.ident);
+                quote!(#name { #(#field_idents),* })
+            }
+            syn::Fields::Unnamed(ref fields) => {
+                let field_idents =
+                    (0..fields.unnamed.len()).map(|index| quote::format_ident!("field_{}", index));
+                quote!(#name(#(#field_idents),*))
+            }
+            syn::Fields::Unit => quote!(#name),
+        }
+    }
+
+    // Generate an enum for each subset option which mirrors the variants of the group,
+    // with the conversions from and into the enum
+    fn gen_subset_exprs(&self) -> Vec<proc_macro2::TokenStream> {
+        let name = self.name;
+        let derives = &self.options.subset_derives;
+        let derive_attr = if derives.is_empty() {
+            quote!()
+        } else {
+            quote!(#[derive(#(#derives),*)])
+        };
+        let non_exhaustive = self
+            .attrs
+            .iter()
+            .filter(|attr| attr.path.is_ident("non_exhaustive"));
+        let non_exhaustive = quote!(#(#non_exhaustive)*);
+        self.options
+            .subsets
+            .iter()
+            .filter_map(|(label, subset_name)| {
+                let group = self.groups.iter().find(|group| group.label == *label)?;
+                let visible = self.gen_group_visible(label);
+                let doc = format!(" The variants of `{}` in the group `{}`.", name, label);
+                let variant_exprs = group.variants.iter().map(|variant| {
+                    let attrs = Self::gen_passthrough_attrs(&variant.attrs);
+                    let variant_name = &variant.ident;
+                    let fields = variant.fields.iter().map(|field| {
+                        let attrs = Self::gen_passthrough_attrs(&field.attrs);
+                        let field_ident = &field.ident;
+                        let colon = &field.colon_token;
+                        let ty = &field.ty;
+                        quote!(#(#attrs)* #field_ident #colon #ty)
+                    });
+                    match variant.fields {
+                        syn::Fields::Named(_) => quote!(#(#attrs)* #variant_name { #(#fields),* }),
+                        syn::Fields::Unnamed(_) => quote!(#(#attrs)* #variant_name(#(#fields),*)),
+                        syn::Fields::Unit => quote!(#(#attrs)* #variant_name),
+                    }
+                });
+                let variant_cfgs: Vec<_> = group
+                    .variants
+                    .iter()
+                    .map(Self::gen_variant_cfgs)
+                    .collect();
+                let variant_bindings: Vec<_> = group
+                    .variants
+                    .iter()
+                    .map(Self::gen_variant_bindings)
+                    .collect();
+                Some(quote!(
+                    #[doc = #doc]
+                    #derive_attr
+                    #non_exhaustive
+                    #visible enum #subset_name {
+                        #(#variant_exprs),*
+                    }
+
+                    impl ::core::convert::From<#subset_name> for #name {
+                        #[inline]
+                        fn from(value: #subset_name) -> Self {
+                            match value {
+                                #(#(#variant_cfgs)* #subset_name::#variant_bindings => Self::#variant_bindings,)*
+                            }
+                        }
+                    }
+
+                    impl ::core::convert::TryFrom<#name> for #subset_name {
+                        type Error = #name;
+
+                        #[inline]
+                        #[allow(unreachable_patterns)]
+                        fn try_from(value: #name) -> ::core::result::Result<Self, #name> {
+                            match value {
+                                #(#(#variant_cfgs)* #name::#variant_bindings => ::core::result::Result::Ok(Self::#variant_bindings),)*
+                                value => ::core::result::Result::Err(value),
+                            }
+                        }
+                    }
+                ))
+            })
+            .collect()
+    }
+
+    // Generate the implementations of the user traits bound to the group label names,
+    // each of which has the judgment function of its group label
+    fn gen_impl_trait_exprs(&self) -> Vec<proc_macro2::TokenStream> {
+        let name = self.name;
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+        self.options
+            .impl_traits
+            .iter()
+            .filter_map(|(label, path)| {
+                let group = self.groups.iter().find(|group| group.label == *label)?;
+                let group_fn_expr = self.gen_group_fn_expr(
+                    quote!(),
+                    &syn::Visibility::Inherited,
+                    &self.gen_group_label_ident(label),
+                    &group.variants,
+                );
+                Some(quote!(
+                    impl #impl_generics #path for #name #ty_generics #where_clause {
+                        #group_fn_expr
+                    }
+                ))
+            })
+            .collect()
+    }
+
+    // Generate a trait which declares the judgment functions of the group label names,
+    // so they can be imported selectively and implemented for other types
+    fn gen_group_trait_expr(&self) -> proc_macro2::TokenStream {
+        let trait_name = match self.options.trait_name {
+            Some(ref trait_name) => trait_name,
+            None => return quote!(),
+        };
+        let visible = &self.visible;
+        let name = self.name;
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+        let doc = format!(
+            " The judgment functions of the group label names of `{}`.",
+            name
+        );
+        let fn_decls: Vec<_> = self
+            .groups
+            .iter()
+            .map(|group| {
+                let doc = self.gen_group_fn_doc(group);
+                let label_ident = self.gen_group_label_ident(&group.label);
+                quote!(
+                    #doc
+                    fn #label_ident(&self) -> bool;
+                )
+            })
+            .collect();
+        let group_fn_exprs = self.gen_group_fn_exprs();
+        quote!(
+            #[doc = #doc]
+            #visible trait #trait_name {
+                #(#fn_decls)*
+            }
+
+            impl #impl_generics #trait_name for #name #ty_generics #where_clause {
+                #(#group_fn_exprs)*
+            }
+        )
+    }
+
+    fn gen_group_descriptor_ident(&self) -> syn::Ident {
+        syn::Ident::new(&format!("{}GroupDescriptor", self.name), self.name.span())
+    }
+
+    // Generate a struct which describes a group and its variants at runtime
+    fn gen_group_descriptor_expr(&self) -> proc_macro2::TokenStream {
+        let visible = &self.visible;
+        let group_descriptor_ident = self.gen_group_descriptor_ident();
+        quote!(
+            #[allow(dead_code)]
+            #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+            #visible struct #group_descriptor_ident {
+                #visible name: &'static str,
+                #visible description: ::core::option::Option<&'static str>,
+                #visible variants: &'static [&'static str],
+            }
+        )
+    }
+
+    // Generate the descriptor literal of each group, built by the path of the descriptor struct
+    fn gen_group_descriptor_entries(
+        &self,
+        group_descriptor_path: &proc_macro2::TokenStream,
+    ) -> Vec<proc_macro2::TokenStream> {
+        self.shared_groups()
+            .map(|group| {
+                let name = syn::LitStr::new(&group.label.to_string(), group.label.span());
+                let description = match self.gen_group_description(&group.label) {
+                    Some(description) => quote!(::core::option::Option::Some(#description)),
+                    None => quote!(::core::option::Option::None),
+                };
+                let variant_names: Vec<_> = group
+                    .variants
+                    .iter()
+                    .map(Self::gen_variant_name_entry)
+                    .collect();
+                quote!(
+                    #group_descriptor_path {
+                        name: #name,
+                        description: #description,
+                        variants: &[#(#variant_names),*],
+                    }
+                )
+            })
+            .collect()
+    }
+
+    // Generate a function that returns the descriptors of all the groups,
+    // the descriptor struct is generated for each enum because the derive crate cannot export runtime types
+    fn gen_group_descriptors_fn_expr(&self) -> proc_macro2::TokenStream {
+        let visible = &self.visible;
+        let group_descriptor_ident = self.gen_group_descriptor_ident();
+        let descriptors = self.gen_group_descriptor_entries(&quote!(#group_descriptor_ident));
+        quote!(
+            #[inline]
+            #visible fn group_descriptors() -> &'static [#group_descriptor_ident] {
+                const DESCRIPTORS: &[#group_descriptor_ident] = &[#(#descriptors),*];
+                DESCRIPTORS
+            }
+        )
+    }
+
+    // Generate a fieldless enum which contains one variant for each group label name
+    fn gen_group_enum_expr(&self) -> proc_macro2::TokenStream {
+        let visible = &self.visible;
+        let group_enum_ident = self.gen_group_enum_ident();
+        let group_variant_idents: Vec<_> = self
+            .shared_groups()
+            .map(|group| Self::gen_group_variant_ident(&group.label))
+            .collect();
+        let group_indexes: Vec<_> = (0..group_variant_idents.len()).collect();
+        quote!(
+            #[allow(dead_code)]
+            #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+            #visible enum #group_enum_ident {
+                #(#group_variant_idents),*
+            }
+
+            impl #group_enum_ident {
+                #visible const ALL: &'static [Self] = &[#(Self::#group_variant_idents),*];
+
+                #[inline]
+                #visible const fn index(self) -> usize {
+                    match self {
+                        #(Self::#group_variant_idents => #group_indexes),*
+                    }
+                }
+            }
+        )
+    }
+
+    // Generate the ident of the group set by suffixing the enum name with "GroupSet"
+    fn gen_group_set_ident(&self) -> syn::Ident {
+        syn::Ident::new(&format!("{}GroupSet", self.name), self.name.span())
+    }
+
+    // Generate the words of the bitset which contains the group labels at the indexes
+    fn gen_group_set_words(&self, indexes: impl Iterator<Item = usize>) -> Vec<u64> {
+        let mut words = vec![0u64; std::cmp::max(1, self.shared_groups().count().div_ceil(64))];
+        for index in indexes {
+            words[index / 64] |= 1 << (index % 64);
+        }
+        words
+    }
+
+    // Generate a copyable bitset type which can hold any combination of group labels
+    fn gen_group_set_expr(&self) -> proc_macro2::TokenStream {
+        let visible = &self.visible;
+        let group_enum_ident = self.gen_group_enum_ident();
+        let group_set_ident = self.gen_group_set_ident();
+        let all_words = self.gen_group_set_words(0..self.shared_groups().count());
+        let size = all_words.len();
+        quote!(
+            #[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+            #visible struct #group_set_ident {
+                bits: [u64; #size],
+            }
+
+            impl #group_set_ident {
+                #[inline]
+                #visible const fn empty() -> Self {
+                    Self { bits: [0; #size] }
+                }
+
+                #[inline]
+                #visible const fn all() -> Self {
+                    Self { bits: [#(#all_words),*] }
+                }
+
+                #[inline]
+                #visible const fn from_group(group: #group_enum_ident) -> Self {
+                    Self::empty().with(group)
+                }
+
+                #[inline]
+                #visible const fn with(self, group: #group_enum_ident) -> Self {
+                    let index = group.index();
+                    let mut bits = self.bits;
+                    bits[index / 64] |= 1 << (index % 64);
+                    Self { bits }
+                }
+
+                #[inline]
+                #visible const fn without(self, group: #group_enum_ident) -> Self {
+                    let index = group.index();
+                    let mut bits = self.bits;
+                    bits[index / 64] &= !(1 << (index % 64));
+                    Self { bits }
+                }
+
+                #[inline]
+                #visible const fn contains(&self, group: #group_enum_ident) -> bool {
+                    let index = group.index();
+                    self.bits[index / 64] & (1 << (index % 64)) != 0
+                }
+
+                #[inline]
+                #visible const fn union(self, other: Self) -> Self {
+                    let mut bits = self.bits;
+                    let mut i = 0;
+                    while i < #size {
+                        bits[i] |= other.bits[i];
+                        i += 1;
+                    }
+                    Self { bits }
+                }
+
+                #[inline]
+                #visible const fn intersection(self, other: Self) -> Self {
+                    let mut bits = self.bits;
+                    let mut i = 0;
+                    while i < #size {
+                        bits[i] &= other.bits[i];
+                        i += 1;
+                    }
+                    Self { bits }
+                }
+
+                #[inline]
+                #visible const fn difference(self, other: Self) -> Self {
+                    let mut bits = self.bits;
+                    let mut i = 0;
+                    while i < #size {
+                        bits[i] &= !other.bits[i];
+                        i += 1;
+                    }
+                    Self { bits }
+                }
+
+                #[inline]
+                #visible const fn is_empty(&self) -> bool {
+                    let mut i = 0;
+                    while i < #size {
+                        if self.bits[i] != 0 {
+                            return false;
+                        }
+                        i += 1;
+                    }
+                    true
+                }
+
+                #[inline]
+                #visible const fn len(&self) -> usize {
+                    let mut count = 0;
+                    let mut i = 0;
+                    while i < #size {
+                        count += self.bits[i].count_ones() as usize;
+                        i += 1;
+                    }
+                    count
+                }
+
+                #[inline]
+                #visible const fn intersects(&self, other: Self) -> bool {
+                    !self.intersection(other).is_empty()
+                }
+
+                #[inline]
+                #visible const fn is_subset(&self, other: Self) -> bool {
+                    self.difference(other).is_empty()
+                }
+
+                #[inline]
+                #visible const fn is_superset(&self, other: Self) -> bool {
+                    other.difference(*self).is_empty()
+                }
+
+                #[inline]
+                #visible fn insert(&mut self, group: #group_enum_ident) -> bool {
+                    let contained = self.contains(group);
+                    *self = self.with(group);
+                    !contained
+                }
+
+                #[inline]
+                #visible fn remove(&mut self, group: #group_enum_ident) -> bool {
+                    let contained = self.contains(group);
+                    *self = self.without(group);
+                    contained
+                }
+
+                #[inline]
+                #visible fn iter(&self) -> impl ::core::iter::Iterator<Item = #group_enum_ident> {
+                    let set = *self;
+                    #group_enum_ident::ALL
+                        .iter()
+                        .copied()
+                        .filter(move |group| set.contains(*group))
+                }
+            }
+
+            impl ::core::fmt::Debug for #group_set_ident {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    f.debug_set().entries(self.iter()).finish()
+                }
+            }
+
+            impl ::core::convert::From<#group_enum_ident> for #group_set_ident {
+                #[inline]
+                fn from(group: #group_enum_ident) -> Self {
+                    Self::from_group(group)
+                }
+            }
+
+            impl ::core::iter::FromIterator<#group_enum_ident> for #group_set_ident {
+                fn from_iter<I: ::core::iter::IntoIterator<Item = #group_enum_ident>>(iter: I) -> Self {
+                    iter.into_iter().fold(Self::empty(), Self::with)
+                }
+            }
+
+            impl ::core::iter::Extend<#group_enum_ident> for #group_set_ident {
+                fn extend<I: ::core::iter::IntoIterator<Item = #group_enum_ident>>(&mut self, iter: I) {
+                    for group in iter {
+                        self.insert(group);
+                    }
+                }
+            }
+
+            impl ::core::ops::BitOr for #group_set_ident {
+                type Output = Self;
+
+                #[inline]
+                fn bitor(self, other: Self) -> Self {
+                    self.union(other)
+                }
+            }
+
+            impl ::core::ops::BitOrAssign for #group_set_ident {
+                #[inline]
+                fn bitor_assign(&mut self, other: Self) {
+                    *self = self.union(other);
+                }
+            }
+
+            impl ::core::ops::BitAnd for #group_set_ident {
+                type Output = Self;
+
+                #[inline]
+                fn bitand(self, other: Self) -> Self {
+                    self.intersection(other)
+                }
+            }
+
+            impl ::core::ops::BitAndAssign for #group_set_ident {
+                #[inline]
+                fn bitand_assign(&mut self, other: Self) {
+                    *self = self.intersection(other);
+                }
+            }
+
+            impl ::core::ops::Sub for #group_set_ident {
+                type Output = Self;
+
+                #[inline]
+                fn sub(self, other: Self) -> Self {
+                    self.difference(other)
+                }
+            }
+
+            impl ::core::ops::SubAssign for #group_set_ident {
+                #[inline]
+                fn sub_assign(&mut self, other: Self) {
+                    *self = self.difference(other);
+                }
+            }
+        )
+    }
+
+    // Generate a function that returns the set of group labels of each variant
+    fn gen_group_set_fn_expr(&self) -> proc_macro2::TokenStream {
+        let visible = &self.visible;
+        let group_set_ident = self.gen_group_set_ident();
+        let variant_arms: Vec<_> = self.variants.iter().map(Self::gen_variant_arm).collect();
+        let variant_words: Vec<_> = self
+            .variants
+            .iter()
+            .map(|variant| {
+                let words = self.gen_group_set_words(
+                    self.shared_groups()
+                        .enumerate()
+                        .filter(|(_, group)| group.contains(variant))
+                        .map(|(index, _)| index),
+                );
+                quote!(#group_set_ident { bits: [#(#words),*] })
+            })
+            .collect();
+        quote!(
+            #[inline]
+            #visible const fn group_set(&self) -> #group_set_ident {
+                match self {
+                    #(#variant_arms => #variant_words),*
+                }
+            }
+        )
+    }
+
+    // Generate a function that returns an iterator over the group labels of each variant
+    fn gen_groups_fn_expr(&self) -> proc_macro2::TokenStream {
+        let visible = &self.visible;
+        let group_enum_ident = self.gen_group_enum_ident();
+        let variant_arms: Vec<_> = self.variants.iter().map(Self::gen_variant_arm).collect();
+        let variant_groups: Vec<_> = self
+            .variants
+            .iter()
+            .map(|variant| {
+                let group_variant_idents: Vec<_> = self
+                    .shared_groups()
+                    .filter(|group| group.contains(variant))
+                    .map(|group| Self::gen_group_variant_ident(&group.label))
+                    .collect();
+                quote!(&[#(#group_enum_ident::#group_variant_idents),*])
+            })
+            .collect();
+        quote!(
+            #[inline]
+            #visible fn groups(&self) -> impl ::core::iter::Iterator<Item = #group_enum_ident> {
+                let groups: &'static [#group_enum_ident] = match self {
+                    #(#variant_arms => #variant_groups),*
+                };
+                groups.iter().copied()
+            }
+        )
+    }
+
+    // Generate a function that tells whether the variant belongs to the group label
+    fn gen_in_group_fn_expr(&self) -> proc_macro2::TokenStream {
+        let visible = &self.visible;
+        let group_enum_ident = self.gen_group_enum_ident();
+        let group_variant_idents: Vec<_> = self
+            .shared_groups()
+            .map(|group| Self::gen_group_variant_ident(&group.label))
+            .collect();
+        let group_calls: Vec<_> = self
+            .shared_groups()
+            .map(|group| self.gen_group_call_expr(&group.label, quote!(self)))
+            .collect();
+        quote!(
+            #[inline]
+            #visible fn in_group(&self, group: #group_enum_ident) -> bool {
+                match group {
+                    #(#group_enum_ident::#group_variant_idents => #group_calls),*
+                }
+            }
+        )
+    }
+
+    // Generate a function that tells whether the variant belongs to the group which is named by the string
+    fn gen_is_in_group_named_fn_expr(&self) -> proc_macro2::TokenStream {
+        let visible = &self.visible;
+        let group_name_pats: Vec<_> = self
+            .shared_groups()
+            .map(|group| {
+                let mut names = vec![group.label.to_string()];
+                for path in group.paths.iter() {
+                    let names_in_path: Vec<_> = path.iter().map(|i| i.to_string()).collect();
+                    let dotted_name = names_in_path.join(".");
+                    if !names.contains(&dotted_name) {
+                        names.push(dotted_name);
+                    }
+                }
+                let names = names
+                    .iter()
+                    .map(|name| syn::LitStr::new(name, group.label.span()));
+                quote!(#(#names)|*)
+            })
+            .collect();
+        let group_calls: Vec<_> = self
+            .shared_groups()
+            .map(|group| self.gen_group_call_expr(&group.label, quote!(self)))
+            .collect();
+        quote!(
+            #visible fn is_in_group_named(&self, name: &str) -> ::core::option::Option<bool> {
+                match name {
+                    #(#group_name_pats => ::core::option::Option::Some(#group_calls),)*
+                    _ => ::core::option::Option::None,
+                }
+            }
+        )
+    }
+
+    // Generate a function that returns the group label names of each variant
+    fn gen_group_names_fn_expr(&self) -> proc_macro2::TokenStream {
+        let visible = &self.visible;
+        let variant_arms: Vec<_> = self.variants.iter().map(Self::gen_variant_arm).collect();
+        let variant_group_names: Vec<_> = self
+            .variants
+            .iter()
+            .map(|variant| {
+                let names = self
+                    .shared_groups()
+                    .filter(|group| group.contains(variant))
+                    .map(|group| syn::LitStr::new(&group.label.to_string(), group.label.span()));
+                quote!(&[#(#names),*])
+            })
+            .collect();
+        quote!(
+            #[inline]
+            #visible fn group_names(&self) -> &'static [&'static str] {
+                match self {
+                    #(#variant_arms => #variant_group_names),*
+                }
+            }
+        )
+    }
+
+    // Generate a new ident by converting the group label name to upper case and suffixing it
+    fn gen_group_const_ident(label: &syn::Ident, suffix: &str) -> syn::Ident {
+        syn::Ident::new(
+            &format!("{}_{}", label.to_string().to_uppercase(), suffix),
+            label.span(),
+        )
+    }
+
+    // Generate the name of the variant as an entry of the name tables
+    fn gen_variant_name_entry(variant: &syn::Variant) -> proc_macro2::TokenStream {
+        let cfgs = Self::gen_variant_cfgs(variant);
+        let name = syn::LitStr::new(&variant.ident.to_string(), variant.span());
+        quote!(#(#cfgs)* #name)
+    }
+
+    // Generate associated constants which describe the groups and their variants at runtime
+    fn gen_group_table_exprs(&self) -> proc_macro2::TokenStream {
+        let visible = &self.visible;
+        let group_names: Vec<_> = self
+            .shared_groups()
+            .map(|group| syn::LitStr::new(&group.label.to_string(), group.label.span()))
+            .collect();
+        let variant_names: Vec<_> = self
+            .variants
+            .iter()
+            .map(Self::gen_variant_name_entry)
+            .collect();
+        let group_variant_exprs: Vec<_> = self
+            .groups
+            .iter()
+            .map(|group| {
+                let variants_ident = Self::gen_group_const_ident(&group.label, "VARIANTS");
+                let count_ident = Self::gen_group_const_ident(&group.label, "COUNT");
+                let label_visible = self.gen_group_visible(&group.label);
+                let names: Vec<_> = group
+                    .variants
+                    .iter()
+                    .map(Self::gen_variant_name_entry)
+                    .collect();
+                quote!(
+                    #label_visible const #variants_ident: &'static [&'static str] = &[#(#names),*];
+                    #label_visible const #count_ident: usize = Self::#variants_ident.len();
+                )
+            })
+            .collect();
+        let group_count = self.shared_groups().count();
+        let variant_cfgs: Vec<_> = self.variants.iter().map(Self::gen_variant_cfgs).collect();
+        let variant_count = quote!({
+            let mut count = 0;
+            #(#(#variant_cfgs)* { count += 1; })*
+            count
+        });
+        let matrix_rows: Vec<_> = self
+            .variants
+            .iter()
+            .map(|variant| {
+                let cfgs = Self::gen_variant_cfgs(variant);
+                let cells = self.shared_groups().map(|group| group.contains(variant));
+                quote!(#(#cfgs)* [#(#cells),*])
+            })
+            .collect();
+        quote!(
+            #visible const GROUP_NAMES: &'static [&'static str] = &[#(#group_names),*];
+
+            #visible const VARIANT_NAMES: &'static [&'static str] = &[#(#variant_names),*];
+
+            #(#group_variant_exprs)*
+
+            #visible const GROUP_MATRIX: [[bool; #group_count]; #variant_count] = [#(#matrix_rows),*];
+        )
+    }
+
+    // Generate a serial of judgment functions for a group label name
+    fn gen_variant_group_fn_exprs(&self) -> Vec<proc_macro2::TokenStream> {
+        if self.options.skip_variant_predicates {
+            return Vec::new();
+        }
+        self.variants
+            .iter()
+            .map(|variant| {
+                self.gen_group_fn_expr(
+                    Self::gen_variant_fn_attrs(variant),
+                    &self.visible,
+                    &self.gen_variant_label_ident(variant),
+                    std::slice::from_ref(variant),
+                )
+            })
+            .collect()
+    }
+
+    // Collect the prefixes of all the label paths which have sub label paths, in the order of first appearance
+    fn collect_namespace_paths(&self) -> Vec<LabelPath> {
+        let mut namespace_paths: Vec<LabelPath> = Vec::new();
+        for path in self.groups.iter().flat_map(|group| group.paths.iter()) {
+            for end in 1..path.len() {
+                let prefix = &path[..end];
+                if !namespace_paths.iter().any(|p| p == prefix) {
+                    namespace_paths.push(prefix.to_vec());
+                }
+            }
+        }
+        namespace_paths
+    }
+
+    // Generate the ident of the namespace handle by suffixing the enum name with the camel case label path
+    fn gen_namespace_ident(&self, path: &[syn::Ident]) -> syn::Ident {
+        let camel = Self::gen_group_variant_ident(&Self::concat_label_path(path));
+        syn::Ident::new(&format!("{}{}", self.name, camel), camel.span())
+    }
+
+    // Generate the function ident of a namespace segment, which is a raw ident when the segment is a keyword
+    fn gen_namespace_segment_ident(segment: &syn::Ident) -> syn::Ident {
+        let name = segment.to_string();
+        if syn::parse_str::<syn::Ident>(&name).is_ok() {
+            segment.clone()
+        } else {
+            syn::Ident::new_raw(&name, segment.span())
+        }
+    }
+
+    // Generate the generics of the namespace handles, which borrow the enum with an extra lifetime
+    fn gen_namespace_generics(&self) -> syn::Generics {
+        let mut generics = self.generics.clone();
+        generics.params.insert(0, syn::parse_quote!('enum_group));
+        generics
+    }
+
+    // Generate a lightweight handle type for each namespace of the nested label paths,
+    // with the judgment functions of its sub label names and the functions of its sub namespaces
+    fn gen_namespace_exprs(&self) -> Vec<proc_macro2::TokenStream> {
+        if !self.options.namespace {
+            return Vec::new();
+        }
+        let visible = &self.visible;
+        let name = self.name;
+        let (_, ty_generics, _) = self.generics.split_for_impl();
+        let generics = self.gen_namespace_generics();
+        let (impl_generics, handle_ty_generics, where_clause) = generics.split_for_impl();
+        let namespace_paths = self.collect_namespace_paths();
+        let all_paths: Vec<_> = self
+            .groups
+            .iter()
+            .flat_map(|group| group.paths.iter())
+            .flat_map(|path| (1..=path.len()).map(move |end| &path[..end]))
+            .collect();
+        namespace_paths
+            .iter()
+            .map(|namespace_path| {
+                let handle_ident = self.gen_namespace_ident(namespace_path);
+                let mut children: Vec<&[syn::Ident]> = Vec::new();
+                for path in all_paths.iter() {
+                    if path.len() == namespace_path.len() + 1
+                        && path.starts_with(namespace_path)
+                        && !children.contains(path)
+                    {
+                        children.push(path);
+                    }
+                }
+                let fn_exprs = children.iter().map(|child| {
+                    let segment = &child[child.len() - 1];
+                    let predicate_ident = self.gen_predicate_ident(&segment.to_string(), segment.span());
+                    let label = Self::concat_label_path(child);
+                    let label_visible = self.gen_group_visible(&label);
+                    let group_call = self.gen_group_call_expr(&label, quote!(self.0));
+                    let predicate_fn_expr = quote!(
+                        #[inline]
+                        #label_visible fn #predicate_ident(&self) -> bool {
+                            #group_call
+                        }
+                    );
+                    if namespace_paths.iter().any(|p| p == child) {
+                        let segment = Self::gen_namespace_segment_ident(segment);
+                        let sub_handle_ident = self.gen_namespace_ident(child);
+                        quote!(
+                            #predicate_fn_expr
+
+                            #[inline]
+                            #visible fn #segment(&self) -> #sub_handle_ident #handle_ty_generics {
+                                #sub_handle_ident(self.0)
+                            }
+                        )
+                    } else {
+                        predicate_fn_expr
+                    }
+                });
+                quote!(
+                    #visible struct #handle_ident #generics (&'enum_group #name #ty_generics) #where_clause;
+
+                    impl #impl_generics ::core::clone::Clone for #handle_ident #handle_ty_generics #where_clause {
+                        #[inline]
+                        fn clone(&self) -> Self {
+                            *self
+                        }
+                    }
+
+                    impl #impl_generics ::core::marker::Copy for #handle_ident #handle_ty_generics #where_clause {}
+
+                    impl #impl_generics #handle_ident #handle_ty_generics #where_clause {
+                        #(#fn_exprs)*
+                    }
+                )
+            })
+            .collect()
+    }
+
+    // Generate the functions of the enum which return the handles of the top level namespaces
+    fn gen_namespace_fn_exprs(&self) -> Vec<proc_macro2::TokenStream> {
+        if !self.options.namespace {
+            return Vec::new();
+        }
+        let visible = &self.visible;
+        let generics = self.gen_namespace_generics();
+        let (_, handle_ty_generics, _) = generics.split_for_impl();
+        self.collect_namespace_paths()
+            .iter()
+            .filter(|path| path.len() == 1)
+            .map(|path| {
+                let segment = Self::gen_namespace_segment_ident(&path[0]);
+                let handle_ident = self.gen_namespace_ident(path);
+                quote!(
+                    #[inline]
+                    #visible fn #segment<'enum_group>(&'enum_group self) -> #handle_ident #handle_ty_generics {
+                        #handle_ident(self)
+                    }
+                )
+            })
+            .collect()
+    }
+
+    // Generate a trait inheritance declaration that includes a group label name judgment function
+    pub fn generate(&self) -> syn::Result<proc_macro2::TokenStream> {
+        let name = self.name;
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+        let group_fn_exprs = if self.options.trait_name.is_some() {
+            Vec::new()
+        } else {
+            self.gen_group_fn_exprs()
+        };
+        let group_trait_expr = self.gen_group_trait_expr();
+        let impl_trait_exprs = self.gen_impl_trait_exprs();
+        let group_marker_exprs = self.gen_group_marker_exprs();
+        let subset_exprs = self.gen_subset_exprs();
+        let variant_group_fn_exprs = self.gen_variant_group_fn_exprs();
+        let variant_name_fn_expr = self.gen_variant_name_fn_expr();
+        let group_enum_expr = self.gen_group_enum_expr();
+        let groups_fn_expr = self.gen_groups_fn_expr();
+        let in_group_fn_expr = self.gen_in_group_fn_expr();
+        let group_set_expr = self.gen_group_set_expr();
+        let group_descriptor_expr = self.gen_group_descriptor_expr();
+        let group_descriptors_fn_expr = self.gen_group_descriptors_fn_expr();
+        let group_set_fn_expr = self.gen_group_set_fn_expr();
+        let group_table_exprs = self.gen_group_table_exprs();
+        let group_names_fn_expr = self.gen_group_names_fn_expr();
+        let is_in_group_named_fn_expr = self.gen_is_in_group_named_fn_expr();
+        let namespace_exprs = self.gen_namespace_exprs();
+        let namespace_fn_exprs = self.gen_namespace_fn_exprs();
+        let st = quote!(
+            #group_enum_expr
+
+            #(#namespace_exprs)*
+
+            #group_set_expr
+
+            #group_descriptor_expr
+
+            #group_trait_expr
+
+            #(#impl_trait_exprs)*
+
+            #group_marker_exprs
+
+            #(#subset_exprs)*
+
+            impl #impl_generics #name #ty_generics #where_clause {
+                #group_table_exprs
+
+                #(#group_fn_exprs)*
+
+                #(#variant_group_fn_exprs)*
+
+                #variant_name_fn_expr
+
+                #group_names_fn_expr
+
+                #is_in_group_named_fn_expr
+
+                #groups_fn_expr
+
+                #in_group_fn_expr
+
+                #group_set_fn_expr
+
+                #group_descriptors_fn_expr
+
+                #(#namespace_fn_exprs)*
+            }
+        );
+        Ok(st)
+    }
+
+    // Generate the implementation of the runtime trait which is defined in the companion crate
+    pub fn generate_trait_impl(&self) -> syn::Result<proc_macro2::TokenStream> {
+        let name = self.name;
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+        let krate = match self.options.krate {
+            Some(ref krate) => quote!(#krate),
+            None => quote!(::enum_group_core),
+        };
+        let variant_name_match_expr = self.gen_variant_name_match_expr();
+        let descriptors = self.gen_group_descriptor_entries(&quote!(#krate::GroupDescriptor));
+        let st = quote!(
+            impl #impl_generics #krate::EnumGroup for #name #ty_generics #where_clause {
+                #[inline]
+                fn variant_name(&self) -> &'static str {
+                    #variant_name_match_expr
+                }
+
+                #[inline]
+                fn group_names(&self) -> &'static [&'static str] {
+                    Self::group_names(self)
+                }
+
+                #[inline]
+                fn in_group_named(&self, name: &str) -> ::core::option::Option<bool> {
+                    Self::is_in_group_named(self, name)
+                }
+            }
+
+            impl #impl_generics #krate::EnumGroupMeta for #name #ty_generics #where_clause {
+                const GROUPS: &'static [&'static str] = Self::GROUP_NAMES;
+                const VARIANTS: &'static [&'static str] = Self::VARIANT_NAMES;
+                const DESCRIPTORS: &'static [#krate::GroupDescriptor] = &[#(#descriptors),*];
             }
         );
         Ok(st)
@@ -0,0 +1,43 @@
+// @author:    olinex
+// @time:      2022/07/29
+
+// self mods
+
+// use other mods
+
+// use self mods
+
+// Accumulate the errors so that all of them can be reported at once
+#[derive(Default)]
+pub struct Errors {
+    error: Option<syn::Error>,
+}
+
+impl Errors {
+    // Combine a new error into the accumulated errors
+    pub fn push(&mut self, e: syn::Error) {
+        match self.error {
+            Some(ref mut error) => error.combine(e),
+            None => self.error = Some(e),
+        }
+    }
+
+    // Take the value of the result, or accumulate the error of the result
+    pub fn ok<T>(&mut self, result: syn::Result<T>) -> Option<T> {
+        match result {
+            Ok(value) => Some(value),
+            Err(e) => {
+                self.push(e);
+                None
+            }
+        }
+    }
+
+    // Return all the accumulated errors as a single error
+    pub fn finish(self) -> syn::Result<()> {
+        match self.error {
+            Some(e) => Err(e),
+            None => Ok(()),
+        }
+    }
+}
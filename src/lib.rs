@@ -6,6 +6,8 @@ extern crate proc_macro;
 
 // self mods
 mod context;
+mod errors;
+mod options;
 
 // use other mods
 use proc_macro::TokenStream;
@@ -14,7 +16,7 @@ use syn::{parse_macro_input, DeriveInput};
 // use self mods
 use context::EnumGroupContext;
 
-#[proc_macro_derive(EnumGroup, attributes(groups))]
+#[proc_macro_derive(EnumGroup, attributes(groups, enum_group))]
 pub fn derive_enum_group(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     let ctx = match EnumGroupContext::new(&input) {
@@ -27,6 +29,25 @@ pub fn derive_enum_group(input: TokenStream) -> TokenStream {
     }
 }
 
+// Same as `EnumGroup`, but also implement the runtime traits of the `enum-group-core` crate,
+// which re-exports this derive macro as `EnumGroup`
+#[proc_macro_derive(EnumGroupCore, attributes(groups, enum_group))]
+pub fn derive_enum_group_core(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let ctx = match EnumGroupContext::new(&input) {
+        Ok(ctx) => ctx,
+        Err(e) => return e.to_compile_error().into(),
+    };
+    let stream = match ctx.generate() {
+        Ok(stream) => stream,
+        Err(e) => return e.to_compile_error().into(),
+    };
+    match ctx.generate_trait_impl() {
+        Ok(trait_impl) => quote::quote!(#stream #trait_impl).into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
 #[cfg(doctest)]
 mod test_readme {
   macro_rules! external_doc_test {
@@ -0,0 +1,430 @@
+// @author:    olinex
+// @time:      2022/07/26
+
+// self mods
+
+// use other mods
+use syn::ext::IdentExt;
+use syn::parse::{Parse, ParseStream};
+
+// use self mods
+use crate::errors::Errors;
+
+// The case conversion of the variant name when generating the variant judgment function name
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum PredicateCase {
+    Lower,
+    Snake,
+}
+
+// The expression which derives a new group from the other groups
+pub enum GroupExpr {
+    Label(syn::Ident),
+    Any(Vec<GroupExpr>),
+    All(Vec<GroupExpr>),
+    Not(Box<GroupExpr>),
+}
+
+impl Parse for GroupExpr {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let ident: syn::Ident = input.parse()?;
+        if !input.peek(syn::token::Paren) {
+            return Ok(Self::Label(ident));
+        }
+        let content;
+        syn::parenthesized!(content in input);
+        let exprs: Vec<_> = content
+            .parse_terminated::<_, syn::Token![,]>(Self::parse)?
+            .into_iter()
+            .collect();
+        match ident.to_string().as_str() {
+            "any" => Ok(Self::Any(exprs)),
+            "all" => Ok(Self::All(exprs)),
+            "not" if exprs.len() == 1 => Ok(Self::Not(Box::new(exprs.into_iter().next().unwrap()))),
+            "not" => Err(syn::Error::new(
+                ident.span(),
+                "not expression must contain exactly one group expression",
+            )),
+            _ => Err(syn::Error::new(
+                ident.span(),
+                "group expression can only be a group label name, any(...), all(...) or not(...)",
+            )),
+        }
+    }
+}
+
+pub struct EnumGroupOptions {
+    pub krate: Option<syn::Path>,
+    pub predicate_case: Option<PredicateCase>,
+    pub prefix: Option<(syn::Ident, String)>,
+    pub suffix: Option<(syn::Ident, String)>,
+    pub skip_variant_predicates: bool,
+    pub skip_variant_name: bool,
+    pub variant_name: Option<syn::Ident>,
+    pub namespace: bool,
+    pub defines: Vec<(syn::Ident, GroupExpr)>,
+    pub listed_groups: Vec<(syn::Ident, Vec<syn::Ident>)>,
+    pub default_groups: Vec<syn::Ident>,
+    pub labels: Option<Vec<syn::Ident>>,
+    pub label_order: Vec<syn::Ident>,
+    pub descriptions: Vec<(syn::Ident, syn::LitStr)>,
+    pub vis: Option<syn::Visibility>,
+    pub label_vis: Vec<(syn::Ident, syn::Visibility)>,
+    pub trait_name: Option<syn::Ident>,
+    pub impl_traits: Vec<(syn::Ident, syn::Path)>,
+    pub subsets: Vec<(syn::Ident, syn::Ident)>,
+    pub subset_derives: Vec<syn::Path>,
+}
+
+pub struct EnumGroupVariantOptions {
+    pub predicate: Option<syn::Ident>,
+    pub skip_default: bool,
+}
+
+// Check that the option has not been declared yet
+fn check_duplicate<T>(option: &Option<T>, key: &syn::Ident) -> syn::Result<()> {
+    if option.is_some() {
+        Err(syn::Error::new(
+            key.span(),
+            format!("duplicate {} option", key),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+// Parse a string literal as the part of a function name and check the format,
+// the leading part must also start with the characters a-z_ to form a valid identifier
+fn parse_name_part(input: ParseStream, leading: bool) -> syn::Result<String> {
+    input.parse::<syn::Token![=]>()?;
+    let lit: syn::LitStr = input.parse()?;
+    let value = lit.value();
+    if value
+        .chars()
+        .any(|c| !(c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_'))
+    {
+        return Err(syn::Error::new_spanned(
+            lit,
+            "enum_group name option can only contain the characters a-z0-9_",
+        ));
+    }
+    if leading && value.starts_with(|c: char| c.is_ascii_digit()) {
+        return Err(syn::Error::new_spanned(
+            lit,
+            "enum_group prefix option must be empty or start with the characters a-z_",
+        ));
+    }
+    Ok(value)
+}
+
+// Parse all the comma separated options in the enum_group attributes by the option parser
+fn parse_attributes<F>(attributes: &[syn::Attribute], mut parse_option: F) -> syn::Result<()>
+where
+    F: FnMut(&syn::Ident, ParseStream) -> syn::Result<()>,
+{
+    let mut errors = Errors::default();
+    for attribute in attributes.iter() {
+        if !attribute.path.is_ident("enum_group") {
+            continue;
+        }
+        errors.ok(attribute.parse_args_with(|input: ParseStream| {
+            while !input.is_empty() {
+                let key = input.call(syn::Ident::parse_any)?;
+                parse_option(&key, input)?;
+                if input.is_empty() {
+                    break;
+                }
+                input.parse::<syn::Token![,]>()?;
+            }
+            Ok(())
+        }));
+    }
+    errors.finish()
+}
+
+// Generate the error of the unknown option
+fn unknown_option(key: &syn::Ident) -> syn::Error {
+    syn::Error::new(
+        key.span(),
+        format!("unknown option `{}` in enum_group attribute", key),
+    )
+}
+
+impl EnumGroupOptions {
+    // Parse a single `key = value` or `key(...)` option in the enum_group attribute
+    fn parse_option(&mut self, key: &syn::Ident, input: ParseStream) -> syn::Result<()> {
+        match key.to_string().as_str() {
+            "crate" => {
+                check_duplicate(&self.krate, key)?;
+                input.parse::<syn::Token![=]>()?;
+                self.krate = Some(input.parse()?);
+            }
+            "predicate_case" => {
+                check_duplicate(&self.predicate_case, key)?;
+                input.parse::<syn::Token![=]>()?;
+                let lit: syn::LitStr = input.parse()?;
+                self.predicate_case = match lit.value().as_str() {
+                    "lower" => Some(PredicateCase::Lower),
+                    "snake" => Some(PredicateCase::Snake),
+                    _ => {
+                        return Err(syn::Error::new_spanned(
+                            lit,
+                            "predicate_case option must be \"lower\" or \"snake\"",
+                        ))
+                    }
+                };
+            }
+            "prefix" => {
+                check_duplicate(&self.prefix, key)?;
+                self.prefix = Some((key.clone(), parse_name_part(input, true)?));
+            }
+            "suffix" => {
+                check_duplicate(&self.suffix, key)?;
+                self.suffix = Some((key.clone(), parse_name_part(input, false)?));
+            }
+            "namespace" => self.namespace = true,
+            "default_groups" => {
+                let content;
+                syn::parenthesized!(content in input);
+                let labels = content.parse_terminated::<_, syn::Token![,]>(syn::Ident::parse)?;
+                self.label_order.extend(labels.iter().cloned());
+                self.default_groups.extend(labels);
+            }
+            "labels" => {
+                let content;
+                syn::parenthesized!(content in input);
+                let labels = content.parse_terminated::<_, syn::Token![,]>(syn::Ident::parse)?;
+                self.label_order.extend(labels.iter().cloned());
+                self.labels.get_or_insert_with(Vec::new).extend(labels);
+            }
+            "group" => {
+                let content;
+                syn::parenthesized!(content in input);
+                while !content.is_empty() {
+                    let label: syn::Ident = content.parse()?;
+                    content.parse::<syn::Token![=]>()?;
+                    let list;
+                    syn::bracketed!(list in content);
+                    let variants = list.parse_terminated::<_, syn::Token![,]>(syn::Ident::parse)?;
+                    self.label_order.push(label.clone());
+                    self.listed_groups
+                        .push((label, variants.into_iter().collect()));
+                    if content.is_empty() {
+                        break;
+                    }
+                    content.parse::<syn::Token![,]>()?;
+                }
+            }
+            "vis" if input.peek(syn::Token![=]) => {
+                check_duplicate(&self.vis, key)?;
+                input.parse::<syn::Token![=]>()?;
+                let lit: syn::LitStr = input.parse()?;
+                self.vis = Some(lit.parse()?);
+            }
+            "vis" => {
+                let content;
+                syn::parenthesized!(content in input);
+                while !content.is_empty() {
+                    let label: syn::Ident = content.parse()?;
+                    content.parse::<syn::Token![=]>()?;
+                    let lit: syn::LitStr = content.parse()?;
+                    self.label_vis.push((label, lit.parse()?));
+                    if content.is_empty() {
+                        break;
+                    }
+                    content.parse::<syn::Token![,]>()?;
+                }
+            }
+            "trait_name" => {
+                check_duplicate(&self.trait_name, key)?;
+                input.parse::<syn::Token![=]>()?;
+                self.trait_name = Some(input.parse()?);
+            }
+            "impl_trait" => {
+                let content;
+                syn::parenthesized!(content in input);
+                while !content.is_empty() {
+                    let label: syn::Ident = content.parse()?;
+                    content.parse::<syn::Token![=]>()?;
+                    let path: syn::Path = content.parse()?;
+                    self.impl_traits.push((label, path));
+                    if content.is_empty() {
+                        break;
+                    }
+                    content.parse::<syn::Token![,]>()?;
+                }
+            }
+            "subset" => {
+                let content;
+                syn::parenthesized!(content in input);
+                while !content.is_empty() {
+                    let label: syn::Ident = content.parse()?;
+                    content.parse::<syn::Token![=]>()?;
+                    let name: syn::Ident = content.parse()?;
+                    self.subsets.push((label, name));
+                    if content.is_empty() {
+                        break;
+                    }
+                    content.parse::<syn::Token![,]>()?;
+                }
+            }
+            "subset_derive" => {
+                let content;
+                syn::parenthesized!(content in input);
+                let paths = content.parse_terminated::<_, syn::Token![,]>(syn::Path::parse)?;
+                self.subset_derives.extend(paths);
+            }
+            "describe" => {
+                let content;
+                syn::parenthesized!(content in input);
+                while !content.is_empty() {
+                    let label: syn::Ident = content.parse()?;
+                    content.parse::<syn::Token![=]>()?;
+                    let description: syn::LitStr = content.parse()?;
+                    self.descriptions.push((label, description));
+                    if content.is_empty() {
+                        break;
+                    }
+                    content.parse::<syn::Token![,]>()?;
+                }
+            }
+            "define" => {
+                let content;
+                syn::parenthesized!(content in input);
+                while !content.is_empty() {
+                    let name: syn::Ident = content.parse()?;
+                    content.parse::<syn::Token![=]>()?;
+                    let expr: GroupExpr = content.parse()?;
+                    self.label_order.push(name.clone());
+                    self.defines.push((name, expr));
+                    if content.is_empty() {
+                        break;
+                    }
+                    content.parse::<syn::Token![,]>()?;
+                }
+            }
+            "skip" => {
+                let content;
+                syn::parenthesized!(content in input);
+                let items = content.parse_terminated::<_, syn::Token![,]>(syn::Ident::parse)?;
+                for item in items.iter() {
+                    match item.to_string().as_str() {
+                        "variant_predicates" => self.skip_variant_predicates = true,
+                        "variant_name" => self.skip_variant_name = true,
+                        _ => {
+                            return Err(syn::Error::new(
+                                item.span(),
+                                "skip option can only contain variant_predicates and variant_name",
+                            ))
+                        }
+                    }
+                }
+            }
+            "variant_name" => {
+                check_duplicate(&self.variant_name, key)?;
+                input.parse::<syn::Token![=]>()?;
+                let lit: syn::LitStr = input.parse()?;
+                self.variant_name = Some(lit.parse()?);
+            }
+            _ => return Err(unknown_option(key)),
+        }
+        Ok(())
+    }
+
+    // Extract the options from all the enum_group attributes declared on the enum
+    pub fn new(attributes: &[syn::Attribute]) -> syn::Result<Self> {
+        let mut options = Self {
+            krate: None,
+            predicate_case: None,
+            prefix: None,
+            suffix: None,
+            skip_variant_predicates: false,
+            skip_variant_name: false,
+            variant_name: None,
+            namespace: false,
+            defines: Vec::new(),
+            listed_groups: Vec::new(),
+            default_groups: Vec::new(),
+            labels: None,
+            label_order: Vec::new(),
+            descriptions: Vec::new(),
+            vis: None,
+            label_vis: Vec::new(),
+            trait_name: None,
+            impl_traits: Vec::new(),
+            subsets: Vec::new(),
+            subset_derives: Vec::new(),
+        };
+        parse_attributes(attributes, |key, input| options.parse_option(key, input))?;
+        if options.prefix().is_empty() && options.suffix().is_empty() {
+            let mut errors = Errors::default();
+            for (key, _) in options.prefix.iter().chain(options.suffix.iter()) {
+                errors.push(syn::Error::new(
+                    key.span(),
+                    "prefix and suffix options cannot both be empty",
+                ));
+            }
+            errors.finish()?;
+        }
+        if let (Some(trait_name), Some((label, _))) =
+            (&options.trait_name, options.label_vis.first())
+        {
+            let mut error = syn::Error::new(
+                label.span(),
+                "vis option of group labels cannot be used with trait_name option",
+            );
+            error.combine(syn::Error::new(
+                trait_name.span(),
+                "the group judgment functions are generated in this trait",
+            ));
+            return Err(error);
+        }
+        Ok(options)
+    }
+
+    pub fn predicate_case(&self) -> PredicateCase {
+        self.predicate_case.unwrap_or(PredicateCase::Lower)
+    }
+
+    pub fn prefix(&self) -> &str {
+        self.prefix.as_ref().map_or("is_", |(_, prefix)| prefix)
+    }
+
+    pub fn suffix(&self) -> &str {
+        self.suffix.as_ref().map_or("", |(_, suffix)| suffix)
+    }
+
+    pub fn variant_name(&self) -> syn::Ident {
+        match self.variant_name {
+            Some(ref ident) => ident.clone(),
+            None => syn::Ident::new("variant_name", proc_macro2::Span::call_site()),
+        }
+    }
+}
+
+impl EnumGroupVariantOptions {
+    // Parse a single `key = value` or `key(...)` option in the enum_group attribute
+    fn parse_option(&mut self, key: &syn::Ident, input: ParseStream) -> syn::Result<()> {
+        match key.to_string().as_str() {
+            "predicate" => {
+                check_duplicate(&self.predicate, key)?;
+                input.parse::<syn::Token![=]>()?;
+                let lit: syn::LitStr = input.parse()?;
+                self.predicate = Some(lit.parse()?);
+            }
+            "skip_default" => self.skip_default = true,
+            _ => return Err(unknown_option(key)),
+        }
+        Ok(())
+    }
+
+    // Extract the options from all the enum_group attributes declared on the variant
+    pub fn new(attributes: &[syn::Attribute]) -> syn::Result<Self> {
+        let mut options = Self {
+            predicate: None,
+            skip_default: false,
+        };
+        parse_attributes(attributes, |key, input| options.parse_option(key, input))?;
+        Ok(options)
+    }
+}
@@ -0,0 +1,63 @@
+// @author:    olinex
+// @time:      2022/08/02
+
+// self mods
+
+// use other mods
+use enum_group::EnumGroup;
+
+// use self mods
+
+#[derive(EnumGroup)]
+enum TestCfgEnum {
+    #[groups(core)]
+    #[cfg_attr(test, groups(experimental))]
+    Alpha,
+
+    #[groups(core)]
+    #[cfg_attr(not(test), groups(experimental))]
+    Beta,
+
+    #[cfg(not(test))]
+    #[groups(core, experimental)]
+    Gamma,
+
+    #[cfg(test)]
+    #[groups(experimental)]
+    Delta,
+}
+
+#[derive(EnumGroup)]
+enum TestMergedEnum {
+    #[groups(stable)]
+    #[groups(visible)]
+    One,
+
+    #[groups(visible)]
+    Two,
+}
+
+#[test]
+fn test_cfg_attr_group() {
+    assert!(TestCfgEnum::Alpha.is_core());
+    assert!(TestCfgEnum::Alpha.is_experimental());
+    assert!(TestCfgEnum::Beta.is_core());
+    assert!(!TestCfgEnum::Beta.is_experimental());
+}
+
+#[test]
+fn test_cfg_variant() {
+    assert!(TestCfgEnum::Delta.is_experimental());
+    assert_eq!(TestCfgEnum::VARIANT_NAMES, &["Alpha", "Beta", "Delta"]);
+    assert_eq!(TestCfgEnum::CORE_VARIANTS, &["Alpha", "Beta"]);
+    assert_eq!(TestCfgEnum::EXPERIMENTAL_COUNT, 2);
+    assert_eq!(TestCfgEnum::GROUP_MATRIX.len(), 3);
+}
+
+#[test]
+fn test_merged_groups_attribute() {
+    assert!(TestMergedEnum::One.is_stable());
+    assert!(TestMergedEnum::One.is_visible());
+    assert!(!TestMergedEnum::Two.is_stable());
+    assert!(TestMergedEnum::Two.is_visible());
+}
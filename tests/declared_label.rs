@@ -0,0 +1,41 @@
+// @author:    olinex
+// @time:      2022/08/02
+
+// self mods
+
+// use other mods
+use enum_group::EnumGroup;
+
+// use self mods
+
+#[derive(EnumGroup)]
+#[enum_group(labels(stable, experimental, deprecated_api))]
+enum TestDeclaredEnum {
+    #[groups(stable)]
+    Get,
+
+    #[groups(stable)]
+    Put,
+
+    Patch,
+}
+
+#[test]
+fn test_empty_declared_label() {
+    assert!(!TestDeclaredEnum::Get.is_experimental());
+    assert!(!TestDeclaredEnum::Patch.is_experimental());
+    assert!(!TestDeclaredEnum::Patch.is_deprecated_api());
+    assert!(TestDeclaredEnum::EXPERIMENTAL_VARIANTS.is_empty());
+    assert_eq!(TestDeclaredEnum::DEPRECATED_API_COUNT, 0);
+}
+
+#[test]
+fn test_used_declared_label() {
+    assert!(TestDeclaredEnum::Get.is_stable());
+    assert!(TestDeclaredEnum::Put.is_stable());
+    assert!(!TestDeclaredEnum::Patch.is_stable());
+    assert_eq!(
+        TestDeclaredEnum::GROUP_NAMES,
+        &["stable", "experimental", "deprecated_api"]
+    );
+}
@@ -0,0 +1,71 @@
+// @author:    olinex
+// @time:      2022/08/02
+
+// self mods
+
+// use other mods
+use enum_group::EnumGroup;
+
+// use self mods
+
+#[derive(EnumGroup)]
+#[enum_group(default_groups(serializable, loggable))]
+enum TestDefaultEnum {
+    #[groups(readable)]
+    Text,
+
+    #[groups(not(serializable), binary)]
+    Handle,
+
+    #[groups(not(serializable, loggable))]
+    Secret,
+
+    #[enum_group(skip_default)]
+    Internal,
+}
+
+#[derive(EnumGroup)]
+enum TestNotLabelEnum {
+    #[groups(not(equal))]
+    Ne,
+
+    #[groups(equal)]
+    Eq,
+}
+
+#[test]
+fn test_default_group() {
+    assert!(TestDefaultEnum::Text.is_serializable());
+    assert!(TestDefaultEnum::Text.is_loggable());
+    assert!(TestDefaultEnum::Text.is_readable());
+    assert!(TestDefaultEnum::Handle.is_loggable());
+    assert!(TestDefaultEnum::Handle.is_binary());
+}
+
+#[test]
+fn test_excluded_default_group() {
+    assert!(!TestDefaultEnum::Handle.is_serializable());
+    assert!(!TestDefaultEnum::Secret.is_serializable());
+    assert!(!TestDefaultEnum::Secret.is_loggable());
+    assert!(!TestDefaultEnum::Internal.is_serializable());
+    assert!(!TestDefaultEnum::Internal.is_loggable());
+}
+
+#[test]
+fn test_default_group_order() {
+    assert_eq!(
+        TestDefaultEnum::GROUP_NAMES,
+        &["serializable", "loggable", "readable", "binary"]
+    );
+    assert_eq!(TestDefaultEnum::SERIALIZABLE_VARIANTS, &["Text"]);
+    assert_eq!(TestDefaultEnum::LOGGABLE_VARIANTS, &["Text", "Handle"]);
+}
+
+#[test]
+fn test_not_label_without_default_groups() {
+    assert!(TestNotLabelEnum::Ne.is_not_equal());
+    assert!(TestNotLabelEnum::Ne.is_not());
+    assert!(!TestNotLabelEnum::Eq.is_not_equal());
+    assert!(TestNotLabelEnum::Eq.is_equal());
+    assert!(!TestNotLabelEnum::Ne.is_equal());
+}
@@ -0,0 +1,103 @@
+// @author:    olinex
+// @time:      2022/08/01
+
+// self mods
+
+// use other mods
+use enum_group::EnumGroup;
+
+// use self mods
+
+#[derive(EnumGroup)]
+#[enum_group(define(
+    numeric = any(integer, float),
+    eq_only = all(comparable, not(ordering)),
+    unordered_numeric = all(numeric, not(ordering)),
+    nothing = all(integer, float),
+))]
+enum TestDefineEnum {
+    #[groups(integer, comparable, ordering)]
+    I8,
+
+    #[groups(float, comparable)]
+    F32,
+
+    #[groups(comparable)]
+    Bool,
+
+    #[groups(comparable, ordering)]
+    Str,
+
+    Unknown,
+}
+
+#[test]
+fn test_define_any() {
+    assert!(TestDefineEnum::I8.is_numeric());
+    assert!(TestDefineEnum::F32.is_numeric());
+    assert!(!TestDefineEnum::Bool.is_numeric());
+    assert!(!TestDefineEnum::Str.is_numeric());
+    assert!(!TestDefineEnum::Unknown.is_numeric());
+}
+
+#[test]
+fn test_define_all_not() {
+    assert!(!TestDefineEnum::I8.is_eq_only());
+    assert!(TestDefineEnum::F32.is_eq_only());
+    assert!(TestDefineEnum::Bool.is_eq_only());
+    assert!(!TestDefineEnum::Str.is_eq_only());
+    assert!(!TestDefineEnum::Unknown.is_eq_only());
+}
+
+#[test]
+fn test_define_reference() {
+    assert!(!TestDefineEnum::I8.is_unordered_numeric());
+    assert!(TestDefineEnum::F32.is_unordered_numeric());
+    assert!(!TestDefineEnum::Bool.is_unordered_numeric());
+}
+
+#[test]
+fn test_define_empty() {
+    assert!(!TestDefineEnum::I8.is_nothing());
+    assert!(!TestDefineEnum::F32.is_nothing());
+    assert_eq!(TestDefineEnum::NOTHING_COUNT, 0);
+}
+
+#[derive(EnumGroup)]
+#[enum_group(define(numeric = any(integer)))]
+#[enum_group(labels(zeta, alpha, integer), default_groups(zeta), group(alpha = [B]))]
+enum TestDefineOrderEnum {
+    A,
+
+    B,
+}
+
+#[test]
+fn test_define_groups() {
+    assert_eq!(
+        TestDefineEnum::GROUP_NAMES,
+        &[
+            "numeric",
+            "eq_only",
+            "unordered_numeric",
+            "nothing",
+            "integer",
+            "comparable",
+            "ordering",
+            "float"
+        ]
+    );
+    assert_eq!(TestDefineEnum::NUMERIC_VARIANTS, &["I8", "F32"]);
+    assert!(TestDefineEnum::Bool.in_group(TestDefineEnumGroup::EqOnly));
+    assert_eq!(TestDefineEnum::F32.is_in_group_named("numeric"), Some(true));
+}
+
+#[test]
+fn test_define_group_order() {
+    assert_eq!(
+        TestDefineOrderEnum::GROUP_NAMES,
+        &["numeric", "zeta", "alpha", "integer"]
+    );
+    assert!(!TestDefineOrderEnum::A.is_numeric());
+    assert!(TestDefineOrderEnum::B.is_alpha());
+}
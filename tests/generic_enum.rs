@@ -40,3 +40,67 @@ fn test_is_multi_line() {
     assert!(TestGenericEnum::Content("Hello everyone".to_string()).is_multi_line());
 }
 
+
+#[allow(dead_code)]
+#[derive(EnumGroup)]
+enum TestBoundedEnum<T: Clone + std::fmt::Debug, U = String> {
+    #[groups(generic)]
+    Value(T),
+
+    #[groups(generic, defaulted)]
+    Other(U),
+
+    Nothing,
+}
+
+#[allow(dead_code)]
+#[derive(EnumGroup)]
+enum TestWhereEnum<'a, T>
+where
+    T: PartialEq + ?Sized + 'a,
+{
+    #[groups(shared)]
+    Ref(&'a T),
+
+    #[groups(boxed)]
+    Owned(Box<T>),
+}
+
+#[allow(dead_code)]
+#[derive(EnumGroup)]
+enum TestConstGenericEnum<const N: usize, T: Copy = u8> {
+    #[groups(fixed)]
+    Array([T; N]),
+
+    #[groups(dynamic)]
+    List(Vec<T>),
+}
+
+#[test]
+fn test_bounded_generic() {
+    assert!(TestBoundedEnum::<i32>::Value(1).is_generic());
+    assert!(!TestBoundedEnum::<i32>::Value(1).is_defaulted());
+    assert!(TestBoundedEnum::<i32>::Other(String::new()).is_defaulted());
+    assert!(TestBoundedEnum::<i32, u8>::Other(0).is_generic());
+    assert!(!TestBoundedEnum::<i32>::Nothing.is_generic());
+    assert_eq!(TestBoundedEnum::<i32>::Nothing.variant_name(), "Nothing");
+}
+
+#[test]
+fn test_where_clause_generic() {
+    let value = String::from("hello");
+    assert!(TestWhereEnum::<str>::Ref(value.as_str()).is_shared());
+    assert!(!TestWhereEnum::<str>::Ref(value.as_str()).is_boxed());
+    assert!(TestWhereEnum::<str>::Owned(value.into_boxed_str()).is_boxed());
+}
+
+#[test]
+fn test_const_generic() {
+    assert!(TestConstGenericEnum::<2>::Array([1, 2]).is_fixed());
+    assert!(TestConstGenericEnum::<2, u16>::List(vec![1, 2, 3]).is_dynamic());
+    assert!(!TestConstGenericEnum::<4>::List(vec![]).is_fixed());
+    assert_eq!(
+        TestConstGenericEnum::<2>::Array([1, 2]).group_set(),
+        TestConstGenericEnumGroupSet::from(TestConstGenericEnumGroup::Fixed)
+    );
+}
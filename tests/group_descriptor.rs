@@ -0,0 +1,52 @@
+// @author:    olinex
+// @time:      2022/08/02
+
+// self mods
+
+// use other mods
+use enum_group::EnumGroup;
+
+// use self mods
+
+#[allow(dead_code)]
+#[derive(EnumGroup)]
+#[enum_group(describe(integer = "fixed-width signed integers", number = "all the numeric types"))]
+#[enum_group(define(number = any(integer, float)))]
+enum TestDescribeEnum<T> {
+    #[groups(integer)]
+    I8(T),
+
+    #[groups(integer)]
+    I16,
+
+    #[groups(float)]
+    F32,
+
+    Bool,
+}
+
+#[test]
+fn test_group_descriptors() {
+    let descriptors = TestDescribeEnum::<u8>::group_descriptors();
+    assert_eq!(descriptors.len(), 3);
+    assert_eq!(descriptors[0].name, "number");
+    assert_eq!(descriptors[0].description, Some("all the numeric types"));
+    assert_eq!(descriptors[0].variants, &["I8", "I16", "F32"]);
+    assert_eq!(
+        descriptors[1],
+        TestDescribeEnumGroupDescriptor {
+            name: "integer",
+            description: Some("fixed-width signed integers"),
+            variants: &["I8", "I16"],
+        }
+    );
+    assert_eq!(descriptors[2].name, "float");
+    assert_eq!(descriptors[2].description, None);
+    assert_eq!(descriptors[2].variants, &["F32"]);
+}
+
+#[test]
+fn test_described_group_predicate() {
+    assert!(TestDescribeEnum::<u8>::I16.is_integer());
+    assert!(!TestDescribeEnum::<u8>::Bool.is_number());
+}
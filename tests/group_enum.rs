@@ -0,0 +1,88 @@
+// @author:    olinex
+// @time:      2022/07/22
+
+// self mods
+
+// use other mods
+use enum_group::EnumGroup;
+
+// use self mods
+
+#[allow(dead_code)]
+#[derive(EnumGroup)]
+enum TestGroupEnum {
+    #[groups(integer, comparable, ordering)]
+    I8(i8),
+
+    #[groups(integer, comparable, ordering)]
+    I16(i16),
+
+    #[groups(comparable)]
+    Bool(bool),
+
+    #[groups(comparable, ordering, multi_byte)]
+    Str(String),
+
+    Unknown,
+}
+
+#[test]
+fn test_groups() {
+    let mut groups: Vec<_> = TestGroupEnum::I8(0).groups().collect();
+    assert_eq!(
+        groups,
+        vec![
+            TestGroupEnumGroup::Integer,
+            TestGroupEnumGroup::Comparable,
+            TestGroupEnumGroup::Ordering
+        ]
+    );
+
+    groups = TestGroupEnum::Bool(true).groups().collect();
+    assert_eq!(groups, vec![TestGroupEnumGroup::Comparable]);
+
+    groups = TestGroupEnum::Str(String::new()).groups().collect();
+    assert_eq!(groups.len(), 3);
+    assert!(groups.contains(&TestGroupEnumGroup::MultiByte));
+
+    assert_eq!(TestGroupEnum::Unknown.groups().count(), 0);
+}
+
+#[test]
+fn test_in_group() {
+    assert!(TestGroupEnum::I8(0).in_group(TestGroupEnumGroup::Integer));
+    assert!(TestGroupEnum::I16(0).in_group(TestGroupEnumGroup::Ordering));
+    assert!(!TestGroupEnum::Bool(true).in_group(TestGroupEnumGroup::Integer));
+    assert!(TestGroupEnum::Bool(true).in_group(TestGroupEnumGroup::Comparable));
+    assert!(TestGroupEnum::Str(String::new()).in_group(TestGroupEnumGroup::MultiByte));
+    assert!(!TestGroupEnum::Unknown.in_group(TestGroupEnumGroup::Comparable));
+}
+
+#[test]
+fn test_group_as_value() {
+    let rules = [
+        (TestGroupEnumGroup::Integer, "integer"),
+        (TestGroupEnumGroup::MultiByte, "multi byte"),
+    ];
+    let matched: Vec<_> = rules
+        .iter()
+        .filter(|(group, _)| TestGroupEnum::Str(String::new()).in_group(*group))
+        .map(|(_, name)| *name)
+        .collect();
+    assert_eq!(matched, vec!["multi byte"]);
+}
+
+#[test]
+fn test_group_order() {
+    assert_eq!(
+        TestGroupEnumGroup::ALL,
+        &[
+            TestGroupEnumGroup::Integer,
+            TestGroupEnumGroup::Comparable,
+            TestGroupEnumGroup::Ordering,
+            TestGroupEnumGroup::MultiByte,
+        ]
+    );
+    assert_eq!(TestGroupEnumGroup::Integer.index(), 0);
+    assert_eq!(TestGroupEnumGroup::MultiByte.index(), 3);
+}
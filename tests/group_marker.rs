@@ -0,0 +1,47 @@
+// @author:    olinex
+// @time:      2022/08/02
+
+// self mods
+
+// use other mods
+use enum_group::EnumGroup;
+
+// use self mods
+
+#[allow(dead_code)]
+#[derive(EnumGroup)]
+enum Typing<T> {
+    #[groups(integer, number)]
+    I8(T),
+
+    #[groups(float, number)]
+    F32,
+
+    Bool,
+}
+
+use typing_groups::GroupOf;
+
+fn only<G: GroupOf<Typing<u8>>>(values: &[Typing<u8>]) -> usize {
+    values.iter().filter(|v| G::test(v)).count()
+}
+
+#[test]
+fn test_group_marker() {
+    assert!(typing_groups::Integer::test(&Typing::I8(1u8)));
+    assert!(!typing_groups::Integer::test(&Typing::<u8>::F32));
+    assert!(typing_groups::Number::test(&Typing::<u8>::F32));
+    assert!(!typing_groups::Number::test(&Typing::<u8>::Bool));
+    assert_eq!(
+        <typing_groups::Float as GroupOf<Typing<u8>>>::NAME,
+        "float"
+    );
+}
+
+#[test]
+fn test_group_generic_code() {
+    let values = [Typing::I8(1), Typing::F32, Typing::Bool];
+    assert_eq!(only::<typing_groups::Integer>(&values), 1);
+    assert_eq!(only::<typing_groups::Number>(&values), 2);
+    assert_eq!(only::<typing_groups::Float>(&values), 1);
+}
@@ -0,0 +1,55 @@
+// @author:    olinex
+// @time:      2022/07/25
+
+// self mods
+
+// use other mods
+use enum_group::EnumGroup;
+
+// use self mods
+
+#[derive(EnumGroup)]
+enum TestGroupNamedEnum {
+    #[groups(accept(eq, ne, gt, lt), number)]
+    I8,
+
+    #[groups(accept(not, eq, ne), accept_and)]
+    Bool,
+
+    Unknown,
+}
+
+#[test]
+fn test_is_in_group_named() {
+    assert_eq!(TestGroupNamedEnum::I8.is_in_group_named("number"), Some(true));
+    assert_eq!(TestGroupNamedEnum::Bool.is_in_group_named("number"), Some(false));
+    assert_eq!(TestGroupNamedEnum::Unknown.is_in_group_named("number"), Some(false));
+    assert_eq!(TestGroupNamedEnum::I8.is_in_group_named("string"), None);
+    assert_eq!(TestGroupNamedEnum::I8.is_in_group_named("i8"), None);
+}
+
+#[test]
+fn test_is_in_group_named_nested() {
+    assert_eq!(TestGroupNamedEnum::I8.is_in_group_named("accept_gt"), Some(true));
+    assert_eq!(TestGroupNamedEnum::I8.is_in_group_named("accept.gt"), Some(true));
+    assert_eq!(TestGroupNamedEnum::Bool.is_in_group_named("accept_gt"), Some(false));
+    assert_eq!(TestGroupNamedEnum::Bool.is_in_group_named("accept.gt"), Some(false));
+    assert_eq!(TestGroupNamedEnum::Bool.is_in_group_named("accept_and"), Some(true));
+    assert_eq!(TestGroupNamedEnum::Bool.is_in_group_named("accept.and"), None);
+    assert_eq!(TestGroupNamedEnum::Bool.is_in_group_named("accept"), Some(true));
+    assert_eq!(TestGroupNamedEnum::Unknown.is_in_group_named("accept"), Some(false));
+}
+
+#[test]
+fn test_group_names() {
+    assert_eq!(
+        TestGroupNamedEnum::I8.group_names(),
+        &["accept", "accept_eq", "accept_ne", "accept_gt", "accept_lt", "number"]
+    );
+    assert_eq!(
+        TestGroupNamedEnum::Bool.group_names(),
+        &["accept", "accept_eq", "accept_ne", "accept_not", "accept_and"]
+    );
+
+    assert!(TestGroupNamedEnum::Unknown.group_names().is_empty());
+}
@@ -0,0 +1,95 @@
+// @author:    olinex
+// @time:      2022/07/23
+
+// self mods
+
+// use other mods
+use enum_group::EnumGroup;
+
+// use self mods
+
+#[allow(dead_code)]
+#[derive(EnumGroup)]
+enum TestGroupSetEnum {
+    #[groups(integer, comparable, ordering)]
+    I8(i8),
+
+    #[groups(integer, comparable, ordering)]
+    I16(i16),
+
+    #[groups(comparable)]
+    Bool(bool),
+
+    #[groups(comparable, ordering, multi_byte)]
+    Str(String),
+
+    Unknown,
+}
+
+const NUMERIC: TestGroupSetEnumGroupSet = TestGroupSetEnumGroupSet::empty()
+    .with(TestGroupSetEnumGroup::Integer)
+    .with(TestGroupSetEnumGroup::Ordering);
+
+#[test]
+fn test_group_set() {
+    let set = TestGroupSetEnum::I8(0).group_set();
+    assert_eq!(set.len(), 3);
+    assert!(set.contains(TestGroupSetEnumGroup::Integer));
+    assert!(set.contains(TestGroupSetEnumGroup::Comparable));
+    assert!(set.contains(TestGroupSetEnumGroup::Ordering));
+    assert!(!set.contains(TestGroupSetEnumGroup::MultiByte));
+
+    assert_eq!(
+        TestGroupSetEnum::Bool(true).group_set(),
+        TestGroupSetEnumGroupSet::from(TestGroupSetEnumGroup::Comparable)
+    );
+    assert!(TestGroupSetEnum::Unknown.group_set().is_empty());
+}
+
+#[test]
+fn test_group_set_mask() {
+    assert!(TestGroupSetEnum::I16(0).group_set().is_superset(NUMERIC));
+    assert!(!TestGroupSetEnum::Str(String::new()).group_set().is_superset(NUMERIC));
+    assert!(TestGroupSetEnum::Str(String::new()).group_set().intersects(NUMERIC));
+    assert!(!TestGroupSetEnum::Bool(true).group_set().intersects(NUMERIC));
+    assert!(!TestGroupSetEnum::Unknown.group_set().intersects(TestGroupSetEnumGroupSet::all()));
+}
+
+#[test]
+fn test_group_set_algebra() {
+    let all = TestGroupSetEnumGroupSet::all();
+    assert_eq!(all.len(), 4);
+    assert_eq!(all - NUMERIC, all.difference(NUMERIC));
+    assert_eq!((all - NUMERIC).len(), 2);
+    assert_eq!(all & NUMERIC, NUMERIC);
+    assert_eq!(NUMERIC | all, all);
+    assert!(NUMERIC.is_subset(all));
+
+    let mut set = TestGroupSetEnumGroupSet::empty();
+    assert!(set.insert(TestGroupSetEnumGroup::MultiByte));
+    assert!(!set.insert(TestGroupSetEnumGroup::MultiByte));
+    set |= NUMERIC;
+    assert_eq!(set.len(), 3);
+    assert!(set.remove(TestGroupSetEnumGroup::Integer));
+    assert!(!set.remove(TestGroupSetEnumGroup::Integer));
+    set &= NUMERIC;
+    assert_eq!(set, TestGroupSetEnumGroupSet::from(TestGroupSetEnumGroup::Ordering));
+    set -= NUMERIC;
+    assert!(set.is_empty());
+}
+
+#[test]
+fn test_group_set_iter() {
+    let set: TestGroupSetEnumGroupSet = TestGroupSetEnum::Str(String::new()).groups().collect();
+    assert_eq!(set, TestGroupSetEnum::Str(String::new()).group_set());
+    assert_eq!(set.iter().count(), 3);
+    assert!(set.iter().all(|group| TestGroupSetEnum::Str(String::new()).in_group(group)));
+    assert_eq!(
+        format!("{:?}", NUMERIC.without(TestGroupSetEnumGroup::Ordering)),
+        "{Integer}"
+    );
+    assert_eq!(
+        format!("{:?}", TestGroupSetEnumGroupSet::all()),
+        "{Integer, Comparable, Ordering, MultiByte}"
+    );
+}
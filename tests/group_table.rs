@@ -0,0 +1,81 @@
+// @author:    olinex
+// @time:      2022/07/24
+
+// self mods
+
+// use other mods
+use enum_group::EnumGroup;
+
+// use self mods
+
+#[allow(dead_code)]
+#[derive(EnumGroup)]
+enum TestGroupTableEnum {
+    #[groups(integer, comparable, ordering)]
+    I8 = 1,
+
+    #[groups(integer, comparable, ordering)]
+    I16 = 2,
+
+    #[groups(comparable)]
+    Bool = 3,
+
+    #[groups(comparable, ordering)]
+    Str = 4,
+
+    Unknown = 0,
+}
+
+#[test]
+fn test_names() {
+    assert_eq!(
+        TestGroupTableEnum::GROUP_NAMES,
+        &["integer", "comparable", "ordering"]
+    );
+    assert_eq!(
+        TestGroupTableEnum::VARIANT_NAMES,
+        &["I8", "I16", "Bool", "Str", "Unknown"]
+    );
+}
+
+#[test]
+fn test_group_variants() {
+    assert_eq!(TestGroupTableEnum::INTEGER_VARIANTS, &["I8", "I16"]);
+    assert_eq!(TestGroupTableEnum::COMPARABLE_VARIANTS, &["I8", "I16", "Bool", "Str"]);
+    assert_eq!(TestGroupTableEnum::ORDERING_VARIANTS, &["I8", "I16", "Str"]);
+}
+
+#[test]
+fn test_group_counts() {
+    let counters = [0usize; TestGroupTableEnum::INTEGER_COUNT];
+    assert_eq!(counters.len(), 2);
+    assert_eq!(TestGroupTableEnum::COMPARABLE_COUNT, 4);
+    assert_eq!(TestGroupTableEnum::ORDERING_COUNT, 3);
+}
+
+#[test]
+fn test_group_matrix() {
+    let matrix = TestGroupTableEnum::GROUP_MATRIX;
+    assert_eq!(matrix.len(), TestGroupTableEnum::VARIANT_NAMES.len());
+    for (row, variant_name) in matrix.iter().zip(TestGroupTableEnum::VARIANT_NAMES) {
+        for (cell, group_name) in row.iter().zip(TestGroupTableEnum::GROUP_NAMES) {
+            let variants = match *group_name {
+                "integer" => TestGroupTableEnum::INTEGER_VARIANTS,
+                "comparable" => TestGroupTableEnum::COMPARABLE_VARIANTS,
+                "ordering" => TestGroupTableEnum::ORDERING_VARIANTS,
+                _ => unreachable!(),
+            };
+            assert_eq!(*cell, variants.contains(variant_name));
+        }
+    }
+    assert_eq!(
+        matrix,
+        [
+            [true, true, true],
+            [true, true, true],
+            [false, true, false],
+            [false, true, true],
+            [false, false, false],
+        ]
+    );
+}
@@ -0,0 +1,75 @@
+// @author:    olinex
+// @time:      2022/08/02
+
+// self mods
+
+// use other mods
+
+// use self mods
+
+mod typing {
+    use enum_group::EnumGroup;
+
+    #[derive(EnumGroup)]
+    #[enum_group(trait_name = TypingGroups, namespace)]
+    pub enum Typing {
+        #[groups(integer, number(exact))]
+        I8,
+
+        #[groups(float)]
+        F32,
+    }
+
+    impl Typing {
+        pub fn is_integer(&self) -> &'static str {
+            "inherent"
+        }
+    }
+}
+
+use typing::{Typing, TypingGroup, TypingGroups};
+
+struct Wrapper(Typing);
+
+impl TypingGroups for Wrapper {
+    fn is_integer(&self) -> bool {
+        TypingGroups::is_integer(&self.0)
+    }
+
+    fn is_number_exact(&self) -> bool {
+        self.0.is_number_exact()
+    }
+
+    fn is_number(&self) -> bool {
+        self.0.is_number()
+    }
+
+    fn is_float(&self) -> bool {
+        self.0.is_float()
+    }
+}
+
+#[test]
+fn test_group_trait() {
+    assert!(TypingGroups::is_integer(&Typing::I8));
+    assert!(!TypingGroups::is_integer(&Typing::F32));
+    assert!(Typing::F32.is_float());
+    assert!(Typing::I8.is_number());
+    assert_eq!(Typing::I8.is_integer(), "inherent");
+}
+
+#[test]
+fn test_group_trait_helpers() {
+    assert!(Typing::I8.in_group(TypingGroup::Integer));
+    assert!(!Typing::F32.in_group(TypingGroup::Integer));
+    assert_eq!(Typing::I8.is_in_group_named("integer"), Some(true));
+    assert!(Typing::I8.number().is_exact());
+    assert!(!Typing::F32.number().is_exact());
+}
+
+#[test]
+fn test_group_trait_for_wrapper() {
+    assert!(Wrapper(Typing::I8).is_integer());
+    assert!(Wrapper(Typing::F32).is_float());
+    assert!(!Wrapper(Typing::F32).is_number());
+}
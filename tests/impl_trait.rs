@@ -0,0 +1,68 @@
+// @author:    olinex
+// @time:      2022/08/02
+
+// self mods
+
+// use other mods
+use enum_group::EnumGroup;
+
+// use self mods
+
+mod traits {
+    pub trait Comparable {
+        fn is_comparable(&self) -> bool;
+    }
+
+    pub trait Ordering {
+        fn is_ordering(&self) -> bool;
+    }
+}
+
+use traits::{Comparable, Ordering};
+
+#[derive(EnumGroup)]
+#[enum_group(impl_trait(comparable = crate::traits::Comparable, ordering = traits::Ordering))]
+enum TestTyping {
+    #[groups(comparable, ordering)]
+    Int,
+
+    #[groups(comparable)]
+    Bool,
+
+    Blob,
+}
+
+#[allow(dead_code)]
+#[derive(EnumGroup)]
+#[enum_group(impl_trait(comparable = Comparable))]
+#[enum_group(labels(comparable))]
+enum TestLiteral<T> {
+    Null,
+
+    Value(T),
+}
+
+fn count_comparable<T: Comparable>(values: &[T]) -> usize {
+    values.iter().filter(|value| value.is_comparable()).count()
+}
+
+#[test]
+fn test_impl_trait() {
+    assert!(Comparable::is_comparable(&TestTyping::Int));
+    assert!(Comparable::is_comparable(&TestTyping::Bool));
+    assert!(!Comparable::is_comparable(&TestTyping::Blob));
+    assert!(Ordering::is_ordering(&TestTyping::Int));
+    assert!(!Ordering::is_ordering(&TestTyping::Bool));
+}
+
+#[test]
+fn test_generic_impl_trait() {
+    assert_eq!(
+        count_comparable(&[TestTyping::Int, TestTyping::Blob, TestTyping::Bool]),
+        2
+    );
+    assert_eq!(
+        count_comparable(&[TestLiteral::Null, TestLiteral::Value(1)]),
+        0
+    );
+}
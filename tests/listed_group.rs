@@ -0,0 +1,78 @@
+// @author:    olinex
+// @time:      2022/08/02
+
+// self mods
+
+// use other mods
+use enum_group::EnumGroup;
+
+// use self mods
+
+#[derive(EnumGroup)]
+#[enum_group(group(integer = [I32, I8, I16], signed = [I8]))]
+#[enum_group(define(number = any(integer, float)))]
+enum TestListedEnum {
+    #[groups(comparable)]
+    I8,
+
+    #[groups(signed)]
+    I16,
+
+    I32,
+
+    #[groups(float, signed)]
+    F32,
+
+    Bool,
+}
+
+#[derive(EnumGroup)]
+#[enum_group(default_groups(zeta), group(alpha = [B]))]
+#[enum_group(labels(omega, zeta, alpha, gamma))]
+enum TestOrderedEnum {
+    #[groups(gamma)]
+    A,
+
+    B,
+}
+
+#[test]
+fn test_listed_group() {
+    assert!(TestListedEnum::I8.is_integer());
+    assert!(TestListedEnum::I16.is_integer());
+    assert!(TestListedEnum::I32.is_integer());
+    assert!(!TestListedEnum::F32.is_integer());
+    assert!(!TestListedEnum::Bool.is_integer());
+}
+
+#[test]
+fn test_merged_group() {
+    assert!(TestListedEnum::I8.is_signed());
+    assert!(TestListedEnum::I16.is_signed());
+    assert!(!TestListedEnum::I32.is_signed());
+    assert!(TestListedEnum::F32.is_signed());
+    assert_eq!(TestListedEnum::SIGNED_VARIANTS, &["I8", "I16", "F32"]);
+}
+
+#[test]
+fn test_listed_group_order() {
+    assert_eq!(TestListedEnum::INTEGER_VARIANTS, &["I8", "I16", "I32"]);
+    assert_eq!(
+        TestListedEnum::GROUP_NAMES,
+        &["integer", "signed", "number", "comparable", "float"]
+    );
+    assert!(TestListedEnum::I32.is_number());
+    assert!(!TestListedEnum::Bool.is_number());
+}
+
+#[test]
+fn test_enum_option_group_order() {
+    assert_eq!(
+        TestOrderedEnum::GROUP_NAMES,
+        &["zeta", "alpha", "omega", "gamma"]
+    );
+    assert!(TestOrderedEnum::A.is_zeta());
+    assert!(TestOrderedEnum::B.is_alpha());
+    assert!(TestOrderedEnum::A.is_gamma());
+    assert!(!TestOrderedEnum::A.is_omega());
+}
@@ -0,0 +1,91 @@
+// @author:    olinex
+// @time:      2022/07/31
+
+// self mods
+
+// use other mods
+use enum_group::EnumGroup;
+
+// use self mods
+
+#[derive(EnumGroup)]
+#[enum_group(namespace)]
+enum TestNamespaceEnum {
+    #[groups(accept(cmp(eq, ne, gte), arith(add)), number)]
+    I8,
+
+    #[groups(accept(cmp(eq, ne), not))]
+    Bool,
+
+    Unknown,
+}
+
+#[allow(dead_code)]
+#[derive(EnumGroup)]
+#[enum_group(namespace)]
+enum TestGenericNamespaceEnum<'a, T: Clone>
+where
+    T: PartialEq,
+{
+    #[groups(accept(cmp(eq)))]
+    Value(&'a T),
+
+    #[groups(accept(cmp(ne)))]
+    Owned(T),
+}
+
+#[derive(EnumGroup)]
+#[enum_group(namespace)]
+enum TestKeywordNamespaceEnum {
+    #[groups(accept(type(eq)), match(all))]
+    I8,
+
+    Bool,
+}
+
+#[test]
+fn test_namespace() {
+    assert!(TestNamespaceEnum::I8.accept().cmp().is_eq());
+    assert!(TestNamespaceEnum::I8.accept().cmp().is_gte());
+    assert!(TestNamespaceEnum::I8.accept().arith().is_add());
+    assert!(!TestNamespaceEnum::I8.accept().is_not());
+    assert!(TestNamespaceEnum::Bool.accept().is_not());
+    assert!(TestNamespaceEnum::Bool.accept().cmp().is_ne());
+    assert!(!TestNamespaceEnum::Bool.accept().cmp().is_gte());
+    assert!(!TestNamespaceEnum::Unknown.accept().cmp().is_eq());
+}
+
+#[test]
+fn test_namespace_umbrella() {
+    assert!(TestNamespaceEnum::I8.accept().is_cmp());
+    assert!(TestNamespaceEnum::I8.accept().is_arith());
+    assert!(!TestNamespaceEnum::Bool.accept().is_arith());
+    assert!(!TestNamespaceEnum::Unknown.accept().is_cmp());
+}
+
+#[test]
+fn test_namespace_handle() {
+    let value = TestNamespaceEnum::I8;
+    let accept: TestNamespaceEnumAccept = value.accept();
+    let cmp: TestNamespaceEnumAcceptCmp = accept.cmp();
+    let copied = cmp;
+    assert!(cmp.is_eq() && copied.is_ne());
+    assert!(value.is_accept_cmp_eq());
+    assert!(value.is_number());
+}
+
+#[test]
+fn test_generic_namespace() {
+    let value = 1;
+    assert!(TestGenericNamespaceEnum::Value(&value).accept().cmp().is_eq());
+    assert!(!TestGenericNamespaceEnum::Value(&value).accept().cmp().is_ne());
+    assert!(TestGenericNamespaceEnum::<i32>::Owned(1).accept().cmp().is_ne());
+}
+
+#[test]
+fn test_keyword_namespace() {
+    assert!(TestKeywordNamespaceEnum::I8.accept().r#type().is_eq());
+    assert!(TestKeywordNamespaceEnum::I8.r#match().is_all());
+    assert!(!TestKeywordNamespaceEnum::Bool.accept().r#type().is_eq());
+    assert!(TestKeywordNamespaceEnum::I8.is_accept_type_eq());
+}
@@ -0,0 +1,72 @@
+// @author:    olinex
+// @time:      2022/07/30
+
+// self mods
+
+// use other mods
+use enum_group::EnumGroup;
+
+// use self mods
+
+#[derive(EnumGroup)]
+enum TestNestedEnum {
+    #[groups(accept(cmp(eq, ne), arith(add)))]
+    I8,
+
+    #[groups(accept(cmp(eq)), logic)]
+    Bool,
+
+    #[groups(accept, accept(cmp(eq)))]
+    Str,
+
+    #[groups(reject)]
+    Unknown,
+}
+
+#[test]
+fn test_umbrella_predicates() {
+    assert!(TestNestedEnum::I8.is_accept());
+    assert!(TestNestedEnum::Bool.is_accept());
+    assert!(TestNestedEnum::Str.is_accept());
+    assert!(!TestNestedEnum::Unknown.is_accept());
+
+    assert!(TestNestedEnum::I8.is_accept_cmp());
+    assert!(TestNestedEnum::Bool.is_accept_cmp());
+    assert!(TestNestedEnum::Str.is_accept_cmp());
+    assert!(!TestNestedEnum::Unknown.is_accept_cmp());
+
+    assert!(TestNestedEnum::I8.is_accept_arith());
+    assert!(!TestNestedEnum::Bool.is_accept_arith());
+}
+
+#[test]
+fn test_leaf_predicates() {
+    assert!(TestNestedEnum::I8.is_accept_cmp_eq());
+    assert!(TestNestedEnum::I8.is_accept_cmp_ne());
+    assert!(TestNestedEnum::I8.is_accept_arith_add());
+    assert!(TestNestedEnum::Bool.is_accept_cmp_eq());
+    assert!(!TestNestedEnum::Bool.is_accept_cmp_ne());
+    assert!(!TestNestedEnum::Unknown.is_accept_cmp_eq());
+}
+
+#[test]
+fn test_umbrella_groups() {
+    assert_eq!(
+        TestNestedEnum::GROUP_NAMES,
+        &[
+            "accept",
+            "accept_cmp",
+            "accept_cmp_eq",
+            "accept_cmp_ne",
+            "accept_arith",
+            "accept_arith_add",
+            "logic",
+            "reject"
+        ]
+    );
+    assert_eq!(TestNestedEnum::ACCEPT_VARIANTS, &["I8", "Bool", "Str"]);
+    assert_eq!(TestNestedEnum::ACCEPT_CMP_VARIANTS, &["I8", "Bool", "Str"]);
+    assert!(TestNestedEnum::Bool.in_group(TestNestedEnumGroup::AcceptCmp));
+    assert_eq!(TestNestedEnum::I8.is_in_group_named("accept.cmp"), Some(true));
+    assert_eq!(TestNestedEnum::Unknown.is_in_group_named("accept.cmp"), Some(false));
+}
@@ -0,0 +1,89 @@
+// @author:    olinex
+// @time:      2022/07/27
+
+// self mods
+
+// use other mods
+use enum_group::EnumGroup;
+
+// use self mods
+
+#[derive(EnumGroup)]
+enum TestLowerCaseEnum {
+    #[groups(error)]
+    HTTPError,
+
+    FooBar,
+}
+
+#[derive(EnumGroup)]
+#[enum_group(predicate_case = "snake")]
+enum TestSnakeCaseEnum {
+    #[groups(error)]
+    HTTPError,
+
+    FooBar,
+
+    Float32,
+
+    IOError2Retry,
+
+    #[enum_group(predicate = "is_int8")]
+    I8,
+}
+
+#[derive(EnumGroup)]
+#[enum_group(predicate_case = "snake", prefix = "in_", suffix = "_group")]
+enum TestPrefixSuffixEnum {
+    #[groups(integer)]
+    SmallInt,
+
+    #[groups(float)]
+    Double,
+}
+
+#[derive(EnumGroup)]
+#[enum_group(prefix = "", suffix = "_kind")]
+enum TestSuffixOnlyEnum {
+    #[groups(fruit)]
+    Apple,
+
+    Stone,
+}
+
+#[test]
+fn test_lower_case() {
+    assert!(TestLowerCaseEnum::HTTPError.is_httperror());
+    assert!(TestLowerCaseEnum::FooBar.is_foobar());
+    assert!(TestLowerCaseEnum::HTTPError.is_error());
+}
+
+#[test]
+fn test_snake_case() {
+    assert!(TestSnakeCaseEnum::HTTPError.is_http_error());
+    assert!(!TestSnakeCaseEnum::HTTPError.is_foo_bar());
+    assert!(TestSnakeCaseEnum::FooBar.is_foo_bar());
+    assert!(TestSnakeCaseEnum::Float32.is_float32());
+    assert!(TestSnakeCaseEnum::IOError2Retry.is_io_error2_retry());
+    assert!(TestSnakeCaseEnum::HTTPError.is_error());
+}
+
+#[test]
+fn test_predicate_override() {
+    assert!(TestSnakeCaseEnum::I8.is_int8());
+    assert!(!TestSnakeCaseEnum::Float32.is_int8());
+}
+
+#[test]
+fn test_prefix_suffix() {
+    assert!(TestPrefixSuffixEnum::SmallInt.in_integer_group());
+    assert!(!TestPrefixSuffixEnum::Double.in_integer_group());
+    assert!(TestPrefixSuffixEnum::Double.in_float_group());
+    assert!(TestPrefixSuffixEnum::SmallInt.in_small_int_group());
+    assert!(TestPrefixSuffixEnum::SmallInt.in_group(TestPrefixSuffixEnumGroup::Integer));
+    assert_eq!(TestPrefixSuffixEnum::Double.is_in_group_named("float"), Some(true));
+
+    assert!(TestSuffixOnlyEnum::Apple.fruit_kind());
+    assert!(TestSuffixOnlyEnum::Stone.stone_kind());
+    assert!(!TestSuffixOnlyEnum::Stone.fruit_kind());
+}
@@ -0,0 +1,76 @@
+// @author:    olinex
+// @time:      2022/07/28
+
+// self mods
+
+// use other mods
+use enum_group::EnumGroup;
+
+// use self mods
+
+#[derive(EnumGroup)]
+#[enum_group(skip(variant_predicates, variant_name))]
+enum TestSkipEnum {
+    #[groups(pet)]
+    Cat,
+
+    Tiger,
+}
+
+impl TestSkipEnum {
+    fn is_cat(&self) -> bool {
+        matches!(self, Self::Cat | Self::Tiger)
+    }
+
+    fn variant_name(&self) -> String {
+        "hand written".to_string()
+    }
+}
+
+#[derive(EnumGroup)]
+#[enum_group(skip(variant_predicates), variant_name = "kind_name")]
+enum TestRenameEnum {
+    #[groups(pet)]
+    Dog,
+
+    Wolf,
+}
+
+impl TestRenameEnum {
+    fn variant_name(&self) -> &str {
+        "hand written"
+    }
+}
+
+#[derive(EnumGroup)]
+#[enum_group(skip(variant_predicates))]
+enum TestSkipCollisionEnum {
+    #[groups(bird)]
+    Bird,
+
+    #[groups(bird)]
+    Penguin,
+}
+
+#[test]
+fn test_skip() {
+    assert!(TestSkipEnum::Cat.is_cat());
+    assert!(TestSkipEnum::Tiger.is_cat());
+    assert!(TestSkipEnum::Cat.is_pet());
+    assert!(!TestSkipEnum::Tiger.is_pet());
+    assert_eq!(TestSkipEnum::Cat.variant_name(), "hand written");
+}
+
+#[test]
+fn test_rename_variant_name() {
+    assert_eq!(TestRenameEnum::Dog.kind_name(), "Dog");
+    assert_eq!(TestRenameEnum::Wolf.kind_name(), "Wolf");
+    assert_eq!(TestRenameEnum::Wolf.variant_name(), "hand written");
+    assert!(TestRenameEnum::Dog.is_pet());
+}
+
+#[test]
+fn test_skip_collision() {
+    assert!(TestSkipCollisionEnum::Bird.is_bird());
+    assert!(TestSkipCollisionEnum::Penguin.is_bird());
+}
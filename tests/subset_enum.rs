@@ -0,0 +1,82 @@
+// @author:    olinex
+// @time:      2022/08/02
+
+// self mods
+
+// use other mods
+use enum_group::EnumGroup;
+use std::convert::TryFrom;
+
+// use self mods
+
+#[derive(Debug, PartialEq, EnumGroup)]
+#[enum_group(subset(integer = IntegerTyping, float = FloatTyping, text = TextTyping))]
+#[enum_group(labels(integer, float, text))]
+#[enum_group(subset_derive(Debug, Clone, PartialEq))]
+enum TestTyping {
+    /// An 8-bit signed integer.
+    #[groups(integer)]
+    I8(i8),
+
+    #[groups(integer)]
+    I16 { value: i16, nullable: bool },
+
+    #[groups(float)]
+    F32,
+
+    Bool(bool),
+}
+
+#[derive(EnumGroup)]
+#[enum_group(subset(small = SmallCode))]
+#[non_exhaustive]
+pub enum TestCode {
+    #[groups(small)]
+    One,
+
+    Two,
+}
+
+#[test]
+fn test_subset_from() {
+    assert_eq!(TestTyping::from(IntegerTyping::I8(1)), TestTyping::I8(1));
+    assert_eq!(
+        TestTyping::from(IntegerTyping::I16 {
+            value: 2,
+            nullable: true
+        }),
+        TestTyping::I16 {
+            value: 2,
+            nullable: true
+        }
+    );
+    assert_eq!(TestTyping::from(FloatTyping::F32), TestTyping::F32);
+}
+
+#[test]
+fn test_subset_try_from() {
+    assert_eq!(IntegerTyping::try_from(TestTyping::I8(1)), Ok(IntegerTyping::I8(1)));
+    assert_eq!(
+        IntegerTyping::try_from(TestTyping::Bool(true)),
+        Err(TestTyping::Bool(true))
+    );
+    assert_eq!(FloatTyping::try_from(TestTyping::F32), Ok(FloatTyping::F32));
+    assert_eq!(
+        FloatTyping::try_from(TestTyping::I8(1)),
+        Err(TestTyping::I8(1))
+    );
+}
+
+#[test]
+fn test_empty_subset() {
+    assert_eq!(
+        TextTyping::try_from(TestTyping::F32).unwrap_err(),
+        TestTyping::F32
+    );
+}
+
+#[test]
+fn test_non_exhaustive_subset() {
+    assert!(TestCode::from(SmallCode::One).is_one());
+    assert!(SmallCode::try_from(TestCode::Two).is_err());
+}
@@ -0,0 +1,38 @@
+// @author:    olinex
+// @time:      2022/08/02
+
+// self mods
+
+// use other mods
+use enum_group::EnumGroup;
+
+// use self mods
+
+#[derive(EnumGroup)]
+enum TestAttrEnum {
+    /// The current version of the protocol.
+    #[groups(supported)]
+    Current,
+
+    #[deprecated(note = "use `Current` instead")]
+    #[groups(supported)]
+    Legacy,
+
+    Unknown,
+}
+
+#[test]
+fn test_documented_variant_predicate() {
+    assert!(TestAttrEnum::Current.is_current());
+    assert!(!TestAttrEnum::Unknown.is_current());
+    assert!(TestAttrEnum::Current.is_supported());
+    assert!(!TestAttrEnum::Unknown.is_supported());
+}
+
+#[test]
+#[allow(deprecated)]
+fn test_deprecated_variant_predicate() {
+    assert!(TestAttrEnum::Legacy.is_legacy());
+    assert!(TestAttrEnum::Legacy.is_supported());
+    assert!(!TestAttrEnum::Current.is_legacy());
+}
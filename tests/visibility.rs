@@ -0,0 +1,89 @@
+// @author:    olinex
+// @time:      2022/08/02
+
+// self mods
+
+// use other mods
+use enum_group::EnumGroup;
+
+// use self mods
+
+mod typing {
+    use enum_group::EnumGroup;
+
+    #[derive(EnumGroup)]
+    #[enum_group(vis = "pub(crate)", vis(internal_cache = "pub(super)"))]
+    pub enum Typing {
+        #[groups(integer, internal_cache)]
+        I8,
+
+        #[groups(float)]
+        F32,
+    }
+}
+
+mod private {
+    use enum_group::EnumGroup;
+
+    #[derive(EnumGroup)]
+    #[enum_group(vis = "pub")]
+    pub(crate) enum Shape {
+        #[groups(round)]
+        Circle,
+
+        Square,
+    }
+}
+
+#[derive(EnumGroup)]
+#[enum_group(vis(hidden = ""))]
+pub enum TestVisEnum {
+    #[groups(hidden, shown)]
+    One,
+
+    Two,
+}
+
+#[test]
+fn test_enum_wide_visibility() {
+    assert!(typing::Typing::I8.is_integer());
+    assert!(typing::Typing::F32.is_f32());
+    assert_eq!(typing::Typing::I8.variant_name(), "I8");
+    assert!(private::Shape::Circle.is_round());
+    assert!(!private::Shape::Square.is_round());
+}
+
+#[test]
+fn test_label_visibility() {
+    assert!(typing::Typing::I8.is_internal_cache());
+    assert_eq!(typing::Typing::INTERNAL_CACHE_COUNT, 1);
+    assert!(TestVisEnum::One.is_hidden());
+    assert!(TestVisEnum::One.is_shown());
+}
+
+#[test]
+fn test_label_visibility_shared_items() {
+    assert_eq!(typing::Typing::GROUP_NAMES, &["integer", "float"]);
+    assert_eq!(typing::Typing::GROUP_MATRIX, [[true, false], [false, true]]);
+    assert_eq!(typing::Typing::I8.group_names(), &["integer"]);
+    assert_eq!(typing::Typing::I8.is_in_group_named("internal_cache"), None);
+    assert_eq!(typing::TypingGroup::ALL.len(), 2);
+    assert_eq!(TestVisEnum::One.group_set().len(), 1);
+    assert_eq!(TestVisEnum::group_descriptors().len(), 1);
+}
+
+#[test]
+fn test_label_visibility_marker() {
+    use typing::typing_groups::GroupOf;
+    assert!(typing::typing_groups::InternalCache::test(
+        &typing::Typing::I8
+    ));
+    assert!(!typing::typing_groups::InternalCache::test(
+        &typing::Typing::F32
+    ));
+    assert!(
+        <test_vis_enum_groups::Hidden as test_vis_enum_groups::GroupOf<TestVisEnum>>::test(
+            &TestVisEnum::One
+        )
+    );
+}